@@ -0,0 +1,117 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Runs the [`BundleConfig::before_packaging_command`](tauri_utils::config_v1::BundleConfig)
+//! hook once before any package format is built, and
+//! [`BundleConfig::before_each_package_command`]/[`BundleConfig::after_each_package_command`]
+//! once per [`PackageType`](crate::PackageType), mirroring the `beforePackagingCommand`/
+//! `beforeEachPackageCommand` hooks of comparable packager tooling.
+
+use std::{collections::HashMap, process::Command};
+
+use tauri_utils::config_v1::HookCommand;
+
+/// Base environment every packaging hook receives, regardless of which package format (if any)
+/// it runs for.
+fn command_env(target_triple: &str, arch: &str, family: &str) -> HashMap<String, String> {
+  let mut env = HashMap::new();
+  env.insert("TAURI_ENV_TARGET_TRIPLE".into(), target_triple.into());
+  env.insert("TAURI_ENV_ARCH".into(), arch.into());
+  env.insert("TAURI_ENV_FAMILY".into(), family.into());
+  env
+}
+
+/// Runs the `beforePackagingCommand` hook once, before any package format starts building.
+pub fn run_before_packaging_hook(
+  hook: &HookCommand,
+  target_triple: &str,
+  arch: &str,
+  family: &str,
+) -> crate::Result<()> {
+  run_hook(
+    "beforePackagingCommand",
+    hook,
+    command_env(target_triple, arch, family),
+  )
+}
+
+/// Runs the `beforeEachPackageCommand` hook for `package_type`, with `TAURI_ENV_PACKAGE_TYPE`
+/// set to its short name (e.g. `nsis`, `deb`) so a single script can special-case a specific
+/// format.
+pub fn run_before_each_package_hook(
+  hook: &HookCommand,
+  package_type: &crate::PackageType,
+  target_triple: &str,
+  arch: &str,
+  family: &str,
+) -> crate::Result<()> {
+  let mut env = command_env(target_triple, arch, family);
+  env.insert(
+    "TAURI_ENV_PACKAGE_TYPE".into(),
+    package_type.short_name().to_owned(),
+  );
+  run_hook("beforeEachPackageCommand", hook, env)
+}
+
+/// Runs the `afterEachPackageCommand` hook for `package_type`, the same way
+/// [`run_before_each_package_hook`] does for the before-hook.
+pub fn run_after_each_package_hook(
+  hook: &HookCommand,
+  package_type: &crate::PackageType,
+  target_triple: &str,
+  arch: &str,
+  family: &str,
+) -> crate::Result<()> {
+  let mut env = command_env(target_triple, arch, family);
+  env.insert(
+    "TAURI_ENV_PACKAGE_TYPE".into(),
+    package_type.short_name().to_owned(),
+  );
+  run_hook("afterEachPackageCommand", hook, env)
+}
+
+fn run_hook(name: &str, hook: &HookCommand, mut env: HashMap<String, String>) -> crate::Result<()> {
+  let (script, cwd, hook_env) = match hook {
+    HookCommand::Script(script) if script.is_empty() => return Ok(()),
+    HookCommand::Script(script) => (script.clone(), None, None),
+    HookCommand::ScriptWithOptions { script, cwd, env } => {
+      (script.clone(), cwd.clone(), env.clone())
+    }
+  };
+
+  // Config-declared env takes precedence over the base vars we just computed, matching the
+  // `ScriptWithOptions::env` doc comment ("merged on top of" the runner-provided variables).
+  if let Some(hook_env) = hook_env {
+    env.extend(hook_env);
+  }
+
+  log::info!(action = "Running"; "{} `{}`", name, script);
+
+  #[cfg(target_os = "windows")]
+  let status = Command::new("cmd")
+    .arg("/S")
+    .arg("/C")
+    .arg(&script)
+    .envs(&env)
+    .current_dir(cwd.unwrap_or_else(|| ".".into()))
+    .status()
+    .map_err(|error| crate::Error::GenericError(error.to_string()))?;
+  #[cfg(not(target_os = "windows"))]
+  let status = Command::new("sh")
+    .arg("-c")
+    .arg(&script)
+    .envs(&env)
+    .current_dir(cwd.unwrap_or_else(|| ".".into()))
+    .status()
+    .map_err(|error| crate::Error::GenericError(error.to_string()))?;
+
+  if !status.success() {
+    return Err(crate::Error::GenericError(format!(
+      "{name} `{script}` failed with exit code {}",
+      status.code().unwrap_or_default()
+    )));
+  }
+
+  Ok(())
+}
@@ -0,0 +1,97 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/// Change value of __TAURI_BUNDLE_TYPE static variable to mark which package type it was bundled in
+#[cfg(target_os = "macos")]
+pub fn patch_binary(
+  binary_path: &std::path::PathBuf,
+  package_type: &crate::PackageType,
+) -> crate::Result<()> {
+  let mut file_data = std::fs::read(binary_path).expect("Could not read binary file.");
+
+  match goblin::mach::Mach::parse(&file_data)? {
+    goblin::mach::Mach::Binary(macho) => {
+      let offset = find_bundle_type_offset(&macho).ok_or(crate::Error::MissingBundleTypeVar)?;
+      patch_offset(&mut file_data, offset, package_type)?;
+    }
+    // Universal (fat) binary: the marker lives in each architecture slice, so every slice that
+    // contains one has to be patched, not just the first.
+    goblin::mach::Mach::Fat(fat) => {
+      let mut patched = false;
+      for arch in fat.arches()? {
+        let start = arch.offset as usize;
+        let end = start + arch.size as usize;
+
+        let offset = {
+          let macho = goblin::mach::MachO::parse(&file_data[start..end], 0)?;
+          find_bundle_type_offset(&macho)
+        };
+
+        if let Some(offset) = offset {
+          patch_offset(&mut file_data[start..end], offset, package_type)?;
+          patched = true;
+        }
+      }
+      if !patched {
+        return Err(crate::Error::MissingBundleTypeVar);
+      }
+    }
+  }
+
+  std::fs::write(binary_path, &file_data)
+    .map_err(|error| crate::Error::BinaryWriteError(error.to_string()))?;
+
+  Ok(())
+}
+
+/// Finds the file offset of the `__TAURI_BUNDLE_TYPE` symbol by looking it up in the symbol table
+/// and resolving its virtual address against the `__DATA`/`__const` section it lives in (Mach-O
+/// symbol values are vmaddrs, not file offsets, so we need the section's vmaddr -> fileoff delta).
+///
+/// Mach-O mangles C symbol names with a leading underscore, so `__TAURI_BUNDLE_TYPE` shows up in
+/// the symbol table as `___TAURI_BUNDLE_TYPE`.
+#[cfg(target_os = "macos")]
+fn find_bundle_type_offset(macho: &goblin::mach::MachO<'_>) -> Option<usize> {
+  let symbol_addr = macho.symbols().find_map(|symbol| {
+    let (name, nlist) = symbol.ok()?;
+    (name == "___TAURI_BUNDLE_TYPE").then_some(nlist.n_value)
+  })?;
+
+  for segment in &macho.segments {
+    let sections = segment.sections().ok()?;
+    for (section, _) in sections {
+      if symbol_addr >= section.addr && symbol_addr < section.addr + section.size {
+        let delta = symbol_addr - section.addr;
+        return Some(section.offset as u64 as usize + delta as usize);
+      }
+    }
+  }
+
+  None
+}
+
+#[cfg(target_os = "macos")]
+fn patch_offset(
+  data: &mut [u8],
+  offset: usize,
+  package_type: &crate::PackageType,
+) -> crate::Result<()> {
+  if offset + 3 > data.len() {
+    return Err(crate::Error::BinaryOffsetOutOfRange);
+  }
+
+  let chars = &mut data[offset..offset + 3];
+  match package_type {
+    crate::PackageType::Dmg => chars.copy_from_slice(b"DMG"),
+    crate::PackageType::MacOsBundle => chars.copy_from_slice(b"APP"),
+    _ => {
+      return Err(crate::Error::InvalidPackageType(
+        package_type.short_name().to_owned(),
+        "macos".to_owned(),
+      ))
+    }
+  }
+
+  Ok(())
+}
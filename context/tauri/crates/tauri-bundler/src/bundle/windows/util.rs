@@ -6,6 +6,7 @@ use std::{
   fs::create_dir_all,
   path::{Path, PathBuf},
 };
+use sha2::{Digest, Sha256};
 use ureq::ResponseExt;
 
 use crate::utils::http_utils::download;
@@ -15,6 +16,8 @@ pub const WEBVIEW2_OFFLINE_INSTALLER_X86_URL: &str =
   "https://go.microsoft.com/fwlink/?linkid=2099617";
 pub const WEBVIEW2_OFFLINE_INSTALLER_X64_URL: &str =
   "https://go.microsoft.com/fwlink/?linkid=2124701";
+pub const WEBVIEW2_OFFLINE_INSTALLER_ARM64_URL: &str =
+  "https://go.microsoft.com/fwlink/?linkid=2099616";
 pub const WEBVIEW2_URL_PREFIX: &str =
   "https://msedge.sf.dl.delivery.mp.microsoft.com/filestreamingservice/files/";
 pub const NSIS_OUTPUT_FOLDER_NAME: &str = "nsis";
@@ -45,26 +48,63 @@ pub fn webview2_guid_path(url: &str) -> crate::Result<(String, String)> {
   Ok((guid.into(), filename.into()))
 }
 
-pub fn download_webview2_bootstrapper(base_path: &Path) -> crate::Result<PathBuf> {
+/// Checks `data` against `expected_sha256` (a lowercase hex digest), if one was provided.
+/// Without an expected digest we fall back to just rejecting an empty (i.e. truncated) download.
+fn verify_digest(data: &[u8], expected_sha256: Option<&str>) -> bool {
+  match expected_sha256 {
+    Some(expected) => format!("{:x}", Sha256::digest(data)).eq_ignore_ascii_case(expected),
+    None => !data.is_empty(),
+  }
+}
+
+/// Downloads `url`, verifying the result against [`verify_digest`] before returning it, so a
+/// corrupted or interrupted download never reaches the caller.
+fn download_verified(url: &str, expected_sha256: Option<&str>) -> crate::Result<Vec<u8>> {
+  let data = download(url)?;
+  if !verify_digest(&data, expected_sha256) {
+    return Err(crate::Error::GenericError(format!(
+      "downloaded file from `{url}` failed integrity verification"
+    )));
+  }
+  Ok(data)
+}
+
+pub fn download_webview2_bootstrapper(
+  base_path: &Path,
+  expected_sha256: Option<&str>,
+) -> crate::Result<PathBuf> {
   let file_path = base_path.join("MicrosoftEdgeWebview2Setup.exe");
+  if file_path.exists() && !verify_digest(&std::fs::read(&file_path)?, expected_sha256) {
+    std::fs::remove_file(&file_path)?;
+  }
   if !file_path.exists() {
-    std::fs::write(&file_path, download(WEBVIEW2_BOOTSTRAPPER_URL)?)?;
+    std::fs::write(
+      &file_path,
+      download_verified(WEBVIEW2_BOOTSTRAPPER_URL, expected_sha256)?,
+    )?;
   }
   Ok(file_path)
 }
 
-pub fn download_webview2_offline_installer(base_path: &Path, arch: &str) -> crate::Result<PathBuf> {
-  let url = if arch == "x64" {
-    WEBVIEW2_OFFLINE_INSTALLER_X64_URL
-  } else {
-    WEBVIEW2_OFFLINE_INSTALLER_X86_URL
+pub fn download_webview2_offline_installer(
+  base_path: &Path,
+  arch: &str,
+  expected_sha256: Option<&str>,
+) -> crate::Result<PathBuf> {
+  let url = match arch {
+    "x64" => WEBVIEW2_OFFLINE_INSTALLER_X64_URL,
+    "arm64" => WEBVIEW2_OFFLINE_INSTALLER_ARM64_URL,
+    _ => WEBVIEW2_OFFLINE_INSTALLER_X86_URL,
   };
   let (guid, filename) = webview2_guid_path(url)?;
   let dir_path = base_path.join(guid);
   let file_path = dir_path.join(filename);
+  if file_path.exists() && !verify_digest(&std::fs::read(&file_path)?, expected_sha256) {
+    std::fs::remove_file(&file_path)?;
+  }
   if !file_path.exists() {
     create_dir_all(dir_path)?;
-    std::fs::write(&file_path, download(url)?)?;
+    std::fs::write(&file_path, download_verified(url, expected_sha256)?)?;
   }
   Ok(file_path)
 }
@@ -72,7 +112,8 @@ pub fn download_webview2_offline_installer(base_path: &Path, arch: &str) -> crat
 #[cfg(target_os = "windows")]
 pub fn os_bitness<'a>() -> Option<&'a str> {
   use windows_sys::Win32::System::SystemInformation::{
-    GetNativeSystemInfo, PROCESSOR_ARCHITECTURE_AMD64, PROCESSOR_ARCHITECTURE_INTEL, SYSTEM_INFO,
+    GetNativeSystemInfo, PROCESSOR_ARCHITECTURE_AMD64, PROCESSOR_ARCHITECTURE_ARM64,
+    PROCESSOR_ARCHITECTURE_INTEL, SYSTEM_INFO,
   };
 
   let mut system_info: SYSTEM_INFO = unsafe { std::mem::zeroed() };
@@ -80,6 +121,7 @@ pub fn os_bitness<'a>() -> Option<&'a str> {
   match unsafe { system_info.Anonymous.Anonymous.wProcessorArchitecture } {
     PROCESSOR_ARCHITECTURE_INTEL => Some("x86"),
     PROCESSOR_ARCHITECTURE_AMD64 => Some("x64"),
+    PROCESSOR_ARCHITECTURE_ARM64 => Some("arm64"),
     _ => None,
   }
 }
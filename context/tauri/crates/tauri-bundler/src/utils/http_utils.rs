@@ -0,0 +1,23 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::io::Read;
+
+/// Downloads the contents at `url` into memory.
+///
+/// Honors the standard `ALL_PROXY`/`HTTPS_PROXY`/`HTTP_PROXY` environment variables, including
+/// `socks5://` proxy URLs, the same way [`crate::bundle::windows::util::webview2_guid_path`]
+/// already does for its own request.
+pub fn download(url: &str) -> crate::Result<Vec<u8>> {
+  let agent: ureq::Agent = ureq::Agent::config_builder()
+    .proxy(ureq::Proxy::try_from_env())
+    .build()
+    .into();
+
+  let response = agent.get(url).call().map_err(Box::new)?;
+
+  let mut buf = Vec::new();
+  response.into_body().into_reader().read_to_end(&mut buf)?;
+  Ok(buf)
+}
@@ -0,0 +1,400 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Access control list (ACL) types for the capability-based permission system.
+//!
+//! This replaces the implicit, all-or-nothing [`crate::config_v1::Allowlist`] used by the v1
+//! configuration format with an explicit, opt-in model: an app declares one or more
+//! [`Capability`]s, each of which grants a set of named [`Permission`]s to the windows matching
+//! a label pattern. A command or scope entry is only ever granted through an explicit `allow`
+//! rule, and any matching `deny` rule always wins.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config_v1::Csp;
+
+mod scope;
+pub use scope::{AssetProtocolConfig, CompiledScope, FsScope};
+
+/// A platform a [`Capability`] applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum Target {
+  /// Linux.
+  Linux,
+  /// Windows.
+  Windows,
+  /// macOS.
+  #[serde(rename = "macOS")]
+  MacOS,
+  /// Android.
+  Android,
+  /// iOS.
+  #[serde(rename = "iOS")]
+  Ios,
+}
+
+/// An allow/deny pair of command names granted or revoked by a [`Permission`].
+///
+/// A command that appears in both lists is denied: `deny` always wins over `allow`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct Commands {
+  /// Command names this permission allows.
+  pub allow: Vec<String>,
+  /// Command names this permission denies.
+  pub deny: Vec<String>,
+}
+
+/// A single scope value, e.g. a URL or path pattern. The exact shape is defined by whichever
+/// command consumes the permission's scope, so it is kept as a raw JSON value.
+pub type ScopeValue = serde_json::Value;
+
+/// An allow/deny pair of [`ScopeValue`] entries granted or revoked by a [`Permission`].
+///
+/// A scope entry that matches both lists is denied: `deny` always wins over `allow`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct Scopes {
+  /// Scope entries this permission allows.
+  pub allow: Vec<ScopeValue>,
+  /// Scope entries this permission denies.
+  pub deny: Vec<ScopeValue>,
+}
+
+/// A named set of command and scope access rules that a [`Capability`] can grant to a window.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Permission {
+  /// Identifier of this permission, referenced from [`Capability::permissions`].
+  pub identifier: String,
+  /// The commands this permission allows or denies.
+  #[serde(default)]
+  pub commands: Commands,
+  /// The scope this permission allows or denies.
+  #[serde(default)]
+  pub scope: Scopes,
+}
+
+/// Grants a set of named [`Permission`]s to the windows matching a label pattern.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct Capability {
+  /// Identifier of this capability.
+  pub identifier: String,
+  /// Window label glob patterns this capability applies to, e.g. `main` or `plugin-*`.
+  #[serde(default)]
+  pub windows: Vec<String>,
+  /// Identifiers of the [`Permission`]s granted by this capability.
+  #[serde(default)]
+  pub permissions: Vec<String>,
+  /// Platforms this capability is enabled on. `None` means all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
+}
+
+/// A named group of [`Permission`] identifiers that a [`Capability`] can reference as a single
+/// unit, the same way it references individual permissions in [`Capability::permissions`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PermissionSet {
+  /// Identifier of this permission set, referenced from [`Capability::permissions`].
+  pub identifier: String,
+  /// Identifiers of the [`Permission`]s this set groups together.
+  pub permissions: Vec<String>,
+}
+
+/// An entry in [`SecurityConfig::capabilities`]: either a capability defined inline, or a path to
+/// a file containing one.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum CapabilityEntry {
+  /// An inlined capability.
+  Inline(Capability),
+  /// Path to a file defining a single capability, relative to the `tauri.conf.json` file.
+  Reference(std::path::PathBuf),
+}
+
+/// Security configuration built on top of the capability/permission ACL.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SecurityConfig {
+  /// The Content Security Policy that will be injected on all HTML files on the built application.
+  pub csp: Option<Csp>,
+  /// The capabilities that grant access to commands and their scopes.
+  #[serde(default)]
+  pub capabilities: Vec<CapabilityEntry>,
+  /// Configuration for the asset custom protocol.
+  #[serde(default)]
+  pub asset_protocol: AssetProtocolConfig,
+}
+
+/// The effective commands and scope granted to a window after resolving every [`Capability`]
+/// that applies to it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ResolvedPermission {
+  /// Commands explicitly allowed by at least one matching capability.
+  pub allowed_commands: HashSet<String>,
+  /// Commands explicitly denied by at least one matching capability. Always wins over `allowed_commands`.
+  pub denied_commands: HashSet<String>,
+  /// Scope entries explicitly allowed by at least one matching capability.
+  pub allowed_scope: Vec<ScopeValue>,
+  /// Scope entries explicitly denied by at least one matching capability. Always wins over `allowed_scope`.
+  pub denied_scope: Vec<ScopeValue>,
+}
+
+impl ResolvedPermission {
+  /// Whether `command` is allowed, taking deny precedence into account.
+  pub fn allows_command(&self, command: &str) -> bool {
+    !self.denied_commands.contains(command) && self.allowed_commands.contains(command)
+  }
+}
+
+/// Resolves the effective permission set granted to `window_label` on `target`, by unioning every
+/// [`Capability`] whose `windows` pattern matches the label and whose `platforms` (if set)
+/// includes `target`. A capability's [`Capability::permissions`] identifiers are first expanded
+/// through `permission_sets` (if one matches) before being looked up in `permissions`. Deny rules
+/// from any matching capability always win over allow rules.
+pub fn resolve_window_permissions(
+  capabilities: &[Capability],
+  permissions: &[Permission],
+  permission_sets: &[PermissionSet],
+  window_label: &str,
+  target: Target,
+) -> ResolvedPermission {
+  let mut resolved = ResolvedPermission::default();
+
+  for capability in capabilities {
+    if let Some(platforms) = &capability.platforms {
+      if !platforms.contains(&target) {
+        continue;
+      }
+    }
+
+    if !capability
+      .windows
+      .iter()
+      .any(|pattern| crate::config_v1::http_scope_glob_match(pattern, window_label))
+    {
+      continue;
+    }
+
+    for identifier in &capability.permissions {
+      for permission_id in flatten_permission_identifier(identifier, permission_sets) {
+        let Some(permission) = permissions.iter().find(|p| p.identifier == permission_id) else {
+          continue;
+        };
+
+        resolved
+          .allowed_commands
+          .extend(permission.commands.allow.iter().cloned());
+        resolved
+          .denied_commands
+          .extend(permission.commands.deny.iter().cloned());
+        resolved
+          .allowed_scope
+          .extend(permission.scope.allow.iter().cloned());
+        resolved
+          .denied_scope
+          .extend(permission.scope.deny.iter().cloned());
+      }
+    }
+  }
+
+  resolved
+}
+
+/// Expands `identifier` into the concrete [`Permission`] identifiers it refers to: every
+/// permission in the matching [`PermissionSet`], or just itself if no set has that identifier.
+fn flatten_permission_identifier(
+  identifier: &str,
+  permission_sets: &[PermissionSet],
+) -> Vec<String> {
+  match permission_sets.iter().find(|s| s.identifier == identifier) {
+    Some(set) => set.permissions.clone(),
+    None => vec![identifier.to_string()],
+  }
+}
+
+/// Transpiles a legacy, boolean [`AllowlistConfig`](crate::config_v1::AllowlistConfig) into an
+/// equivalent [`Capability`] + [`PermissionSet`] pair for `target`, granting every window the
+/// same Cargo features the allowlist would have compiled in on that platform. This keeps existing
+/// `tauri.conf.json` files parsing and behaving the same after migrating to the ACL model.
+///
+/// The returned capability still needs a concrete [`Permission`] for each identifier in the
+/// permission set's list (one per allowlist feature) to be registered alongside it; this function
+/// only produces the identifiers, not the permission definitions themselves, since those are
+/// owned by whichever crate implements the corresponding command.
+#[deprecated = "declare capabilities and permissions directly instead of relying on the allowlist compatibility layer"]
+pub fn capability_from_allowlist(
+  allowlist: &crate::config_v1::AllowlistConfig,
+  target: Target,
+) -> (Capability, PermissionSet) {
+  use crate::config_v1::Allowlist;
+
+  let permission_set = PermissionSet {
+    identifier: "allowlist-compat".into(),
+    permissions: allowlist
+      .to_features(target)
+      .into_iter()
+      .map(|feature| format!("allowlist:{feature}"))
+      .collect(),
+  };
+
+  let capability = Capability {
+    identifier: "allowlist-compat".into(),
+    windows: vec!["*".into()],
+    permissions: vec![permission_set.identifier.clone()],
+    platforms: Some(vec![target]),
+  };
+
+  (capability, permission_set)
+}
+
+/// Flattens every [`Capability`] active for `target` (i.e. whose [`Capability::platforms`] is
+/// unset or contains `target`) into the legacy feature identifiers produced by
+/// [`Allowlist::to_features`](crate::config_v1::Allowlist::to_features), so code generation that
+/// still switches on those feature names keeps working while an app migrates off the allowlist
+/// onto capabilities.
+///
+/// Each entry in [`Capability::permissions`] is expected in `plugin:permission-name` form (e.g.
+/// `fs:read-files`) and is flattened to the equivalent `plugin-permission-name` feature name.
+/// Identifiers referencing a [`PermissionSet`] are expanded the same way
+/// [`resolve_window_permissions`] expands them.
+pub fn capability_features_for_target(
+  capabilities: &[Capability],
+  permission_sets: &[PermissionSet],
+  target: Target,
+) -> Vec<String> {
+  let mut features = Vec::new();
+
+  for capability in capabilities {
+    if let Some(platforms) = &capability.platforms {
+      if !platforms.contains(&target) {
+        continue;
+      }
+    }
+
+    for identifier in &capability.permissions {
+      for permission_id in flatten_permission_identifier(identifier, permission_sets) {
+        features.push(permission_id.replace(':', "-"));
+      }
+    }
+  }
+
+  features
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn permission(identifier: &str, allow: &[&str], deny: &[&str]) -> Permission {
+    Permission {
+      identifier: identifier.into(),
+      commands: Commands {
+        allow: allow.iter().map(|s| s.to_string()).collect(),
+        deny: deny.iter().map(|s| s.to_string()).collect(),
+      },
+      scope: Scopes::default(),
+    }
+  }
+
+  fn capability(identifier: &str, windows: &[&str], permissions: &[&str]) -> Capability {
+    Capability {
+      identifier: identifier.into(),
+      windows: windows.iter().map(|s| s.to_string()).collect(),
+      permissions: permissions.iter().map(|s| s.to_string()).collect(),
+      platforms: None,
+    }
+  }
+
+  #[test]
+  fn resolve_window_permissions_matches_glob_window_label() {
+    let capabilities = vec![capability("main-cap", &["main-*"], &["fs:allow"])];
+    let permissions = vec![permission("fs:allow", &["read_file"], &[])];
+
+    let resolved = resolve_window_permissions(
+      &capabilities,
+      &permissions,
+      &[],
+      "main-window",
+      Target::Linux,
+    );
+    assert!(resolved.allows_command("read_file"));
+
+    let resolved = resolve_window_permissions(
+      &capabilities,
+      &permissions,
+      &[],
+      "other-window",
+      Target::Linux,
+    );
+    assert!(!resolved.allows_command("read_file"));
+  }
+
+  #[test]
+  fn resolve_window_permissions_deny_wins_over_allow() {
+    let capabilities = vec![
+      capability("allow-cap", &["main"], &["fs:allow"]),
+      capability("deny-cap", &["main"], &["fs:deny"]),
+    ];
+    let permissions = vec![
+      permission("fs:allow", &["read_file"], &[]),
+      permission("fs:deny", &[], &["read_file"]),
+    ];
+
+    let resolved =
+      resolve_window_permissions(&capabilities, &permissions, &[], "main", Target::Linux);
+
+    assert!(!resolved.allows_command("read_file"));
+    assert!(resolved.denied_commands.contains("read_file"));
+  }
+
+  #[test]
+  fn resolve_window_permissions_respects_platform_gating() {
+    let mut windows_only = capability("windows-cap", &["main"], &["fs:allow"]);
+    windows_only.platforms = Some(vec![Target::Windows]);
+    let capabilities = vec![windows_only];
+    let permissions = vec![permission("fs:allow", &["read_file"], &[])];
+
+    let resolved =
+      resolve_window_permissions(&capabilities, &permissions, &[], "main", Target::Linux);
+    assert!(!resolved.allows_command("read_file"));
+
+    let resolved =
+      resolve_window_permissions(&capabilities, &permissions, &[], "main", Target::Windows);
+    assert!(resolved.allows_command("read_file"));
+  }
+
+  #[test]
+  fn resolve_window_permissions_expands_permission_sets() {
+    let capabilities = vec![capability("main-cap", &["main"], &["fs-set"])];
+    let permission_sets = vec![PermissionSet {
+      identifier: "fs-set".into(),
+      permissions: vec!["fs:allow".into()],
+    }];
+    let permissions = vec![permission("fs:allow", &["read_file"], &[])];
+
+    let resolved = resolve_window_permissions(
+      &capabilities,
+      &permissions,
+      &permission_sets,
+      "main",
+      Target::Linux,
+    );
+    assert!(resolved.allows_command("read_file"));
+  }
+}
@@ -0,0 +1,173 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Glob-based filesystem scoping, used to restrict which paths a feature (such as the asset
+//! protocol) is allowed to read from.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// A glob-based allow/deny filesystem scope.
+///
+/// Accepts either a plain list of glob patterns (all treated as `allow`), or an object with
+/// explicit `allow`/`deny` lists. `deny` patterns always take precedence over `allow` patterns.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", untagged)]
+pub enum FsScope {
+  /// A plain list of glob patterns that are allowed.
+  AllowList(Vec<String>),
+  /// An explicit allow/deny list of glob patterns.
+  Scope {
+    /// Glob patterns that are allowed.
+    #[serde(default)]
+    allow: Vec<String>,
+    /// Glob patterns that are denied. Always wins over `allow`.
+    #[serde(default)]
+    deny: Vec<String>,
+    /// Whether path components starting with a `.` require the `.` to appear literally in the
+    /// pattern for it to match, instead of being matched by a wildcard.
+    #[serde(default, alias = "require-literal-leading-dot")]
+    require_literal_leading_dot: Option<bool>,
+  },
+}
+
+impl Default for FsScope {
+  fn default() -> Self {
+    Self::AllowList(Vec::new())
+  }
+}
+
+/// An [`FsScope`] compiled into allow/deny pattern lists, ready to be queried with
+/// [`CompiledScope::matches`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledScope {
+  allow: Vec<String>,
+  deny: Vec<String>,
+  /// Mirrors the default of `FsAllowlistScope::Scope::require_literal_leading_dot` in `config_v1`: a
+  /// leading dot must appear literally everywhere except Windows, where dotfiles aren't
+  /// generally treated as hidden.
+  require_literal_leading_dot: bool,
+}
+
+impl From<&FsScope> for CompiledScope {
+  fn from(scope: &FsScope) -> Self {
+    match scope {
+      FsScope::AllowList(patterns) => Self {
+        allow: patterns.clone(),
+        deny: Vec::new(),
+        require_literal_leading_dot: cfg!(not(windows)),
+      },
+      FsScope::Scope {
+        allow,
+        deny,
+        require_literal_leading_dot,
+      } => Self {
+        allow: allow.clone(),
+        deny: deny.clone(),
+        require_literal_leading_dot: require_literal_leading_dot.unwrap_or(cfg!(not(windows))),
+      },
+    }
+  }
+}
+
+impl CompiledScope {
+  /// Builds a [`CompiledScope`] from the given [`FsScope`], copying its allow/deny patterns and
+  /// resolving the `require_literal_leading_dot` default. The patterns are kept as strings and are
+  /// not pre-parsed: each [`matches`](Self::matches) call matches them afresh.
+  pub fn compile(scope: &FsScope) -> Self {
+    Self::from(scope)
+  }
+
+  /// Returns `true` only if `path` matches at least one `allow` pattern and no `deny` pattern.
+  ///
+  /// Uses [`crate::config_v1::fs_scope_glob_match`] so `require_literal_leading_dot` is actually
+  /// honored instead of treated as a no-op.
+  pub fn matches(&self, path: &Path) -> bool {
+    let matches = |pattern: &String| {
+      crate::config_v1::fs_scope_glob_match(
+        Path::new(pattern),
+        path,
+        self.require_literal_leading_dot,
+      )
+    };
+
+    if self.deny.iter().any(matches) {
+      return false;
+    }
+
+    self.allow.iter().any(matches)
+  }
+}
+
+/// Configuration for the asset custom protocol, which serves local filesystem files directly to
+/// the webview.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AssetProtocolConfig {
+  /// Whether the asset protocol is enabled or not.
+  #[serde(default)]
+  pub enable: bool,
+  /// The filesystem scope the asset protocol is allowed to serve files from.
+  #[serde(default)]
+  pub scope: FsScope,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn bare_list_allows_matching_patterns() {
+    let scope = FsScope::AllowList(vec!["/data/*".into()]);
+    let compiled = CompiledScope::compile(&scope);
+
+    assert!(compiled.matches(Path::new("/data/file.txt")));
+    assert!(!compiled.matches(Path::new("/other/file.txt")));
+  }
+
+  #[test]
+  fn deny_wins_over_allow() {
+    let scope = FsScope::Scope {
+      allow: vec!["/data/*".into()],
+      deny: vec!["/data/secret.txt".into()],
+      require_literal_leading_dot: None,
+    };
+    let compiled = CompiledScope::compile(&scope);
+
+    assert!(compiled.matches(Path::new("/data/file.txt")));
+    assert!(!compiled.matches(Path::new("/data/secret.txt")));
+  }
+
+  #[test]
+  fn require_literal_leading_dot_hides_dotfiles_by_default_on_unix() {
+    let scope = FsScope::Scope {
+      allow: vec!["/data/*".into()],
+      deny: vec![],
+      require_literal_leading_dot: None,
+    };
+    let compiled = CompiledScope::compile(&scope);
+
+    if cfg!(not(windows)) {
+      assert!(!compiled.matches(Path::new("/data/.hidden")));
+    } else {
+      assert!(compiled.matches(Path::new("/data/.hidden")));
+    }
+    assert!(compiled.matches(Path::new("/data/visible")));
+  }
+
+  #[test]
+  fn require_literal_leading_dot_false_allows_dotfiles() {
+    let scope = FsScope::Scope {
+      allow: vec!["/data/*".into()],
+      deny: vec![],
+      require_literal_leading_dot: Some(false),
+    };
+    let compiled = CompiledScope::compile(&scope);
+
+    assert!(compiled.matches(Path::new("/data/.hidden")));
+  }
+}
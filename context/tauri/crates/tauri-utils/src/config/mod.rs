@@ -0,0 +1,275 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! The RFC#5-shaped Tauri configuration.
+//!
+//! This flattens the v1 `package`/`tauri`/`build` nesting from [`crate::config_v1`] into a
+//! single top-level [`Config`]: `package.productName`/`package.version` become
+//! [`Config::product_name`]/[`Config::version`], `tauri` becomes [`Config::app`], `tauri.bundle`
+//! is hoisted to [`Config::bundle`], and `tauri.updater` moves under the `updater` key of
+//! [`Config::plugins`]. [`migrate`] rewrites a JSON value parsed from the old shape into the new
+//! one in-memory, so both old and new `tauri.conf.json` files deserialize into [`Config`].
+//!
+//! # Stability
+//! This is a core functionality that is not considered part of the stable API.
+//! If you use it, note that it may include breaking changes in the future.
+
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::skip_serializing_none;
+
+use crate::config_v1::{
+  AppUrl, BundleConfig, CliConfig, PatternKind, PluginConfig, SecurityConfig, SystemTrayConfig,
+  UpdaterConfig, WindowConfig,
+};
+pub use crate::config_v1::{BeforeDevCommand, HookCommand};
+
+/// The build configuration, with the `devUrl`/`frontendDist` names introduced by RFC#5.
+///
+/// See more: https://tauri.app/v1/api/config#buildconfig
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BuildConfig {
+  /// The binary used to build and run the application.
+  pub runner: Option<String>,
+  /// The path to the application assets or URL to load in development.
+  ///
+  /// This is usually an URL to a dev server, which serves your application assets
+  /// with live reloading. Most modern JavaScript bundlers provides a way to start a dev server by default.
+  ///
+  /// Renamed from `devPath` by RFC#5; `devPath` is still accepted for backwards compatibility. May
+  /// be omitted if the app is only ever run against `frontend_dist` (one of the two is required).
+  pub dev_url: Option<AppUrl>,
+  /// The path to the application assets or URL to load in production.
+  ///
+  /// When a path relative to the configuration file is provided,
+  /// it is read recursively and all files are embedded in the application binary.
+  /// Tauri then looks for an `index.html` file unless you provide a custom window URL.
+  ///
+  /// Renamed from `distDir` by RFC#5; `distDir` is still accepted for backwards compatibility. May
+  /// be omitted when the app is meant to always load `dev_url` at runtime instead of embedding
+  /// assets (one of the two is required).
+  pub frontend_dist: Option<AppUrl>,
+  /// A shell command to run before `tauri dev` kicks in.
+  pub before_dev_command: Option<BeforeDevCommand>,
+  /// A shell command to run before `tauri build` kicks in.
+  pub before_build_command: Option<HookCommand>,
+  /// A shell command to run before the bundling phase in `tauri build` kicks in.
+  pub before_bundle_command: Option<HookCommand>,
+  /// Features passed to `cargo` commands.
+  pub features: Option<Vec<String>>,
+  /// Whether we should inject the Tauri API on `window.__TAURI__` or not.
+  pub with_global_tauri: bool,
+}
+
+impl Default for BuildConfig {
+  fn default() -> Self {
+    Self {
+      runner: None,
+      dev_url: None,
+      frontend_dist: None,
+      before_dev_command: None,
+      before_build_command: None,
+      before_bundle_command: None,
+      features: None,
+      with_global_tauri: false,
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for BuildConfig {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    struct InnerBuildConfig {
+      runner: Option<String>,
+      #[serde(default, alias = "dev-url", alias = "devPath")]
+      dev_url: Option<AppUrl>,
+      #[serde(default, alias = "frontend-dist", alias = "distDir")]
+      frontend_dist: Option<AppUrl>,
+      #[serde(alias = "before-dev-command")]
+      before_dev_command: Option<BeforeDevCommand>,
+      #[serde(alias = "before-build-command")]
+      before_build_command: Option<HookCommand>,
+      #[serde(alias = "before-bundle-command")]
+      before_bundle_command: Option<HookCommand>,
+      features: Option<Vec<String>>,
+      #[serde(default, alias = "with-global-tauri")]
+      with_global_tauri: bool,
+    }
+
+    let config = InnerBuildConfig::deserialize(deserializer)?;
+
+    if config.dev_url.is_none() && config.frontend_dist.is_none() {
+      return Err(DeError::custom(
+        "at least one of `build > devUrl` or `build > frontendDist` must be set",
+      ));
+    }
+
+    Ok(BuildConfig {
+      runner: config.runner,
+      dev_url: config.dev_url,
+      frontend_dist: config.frontend_dist,
+      before_dev_command: config.before_dev_command,
+      before_build_command: config.before_build_command,
+      before_bundle_command: config.before_bundle_command,
+      features: config.features,
+      with_global_tauri: config.with_global_tauri,
+    })
+  }
+}
+
+/// The application configuration, flattened out of `tauri.conf.json`'s old `tauri` object.
+///
+/// Unlike [`crate::config_v1::TauriConfig`], [`BundleConfig`] is hoisted to [`Config::bundle`]
+/// and [`UpdaterConfig`] moves under the `updater` key of [`Config::plugins`].
+#[skip_serializing_none]
+#[derive(Debug, Default, PartialEq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AppConfig {
+  /// The pattern to use.
+  #[serde(default)]
+  pub pattern: PatternKind,
+  /// The windows configuration.
+  #[serde(default)]
+  pub windows: Vec<WindowConfig>,
+  /// The CLI configuration.
+  pub cli: Option<CliConfig>,
+  /// The allowlist configuration.
+  #[serde(default)]
+  pub allowlist: crate::config_v1::AllowlistConfig,
+  /// Security configuration.
+  #[serde(default)]
+  pub security: SecurityConfig,
+  /// Configuration for app system tray.
+  #[serde(alias = "system-tray")]
+  pub system_tray: Option<SystemTrayConfig>,
+  /// MacOS private API configuration. Enables the transparent background API and sets the `fullScreenEnabled` preference to `true`.
+  #[serde(rename = "macOSPrivateApi", alias = "macos-private-api", default)]
+  pub macos_private_api: bool,
+}
+
+/// The RFC#5-shaped Tauri configuration object.
+///
+/// See the [module documentation](self) for how this relates to [`crate::config_v1::Config`].
+#[skip_serializing_none]
+#[derive(Debug, Default, PartialEq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Config {
+  /// The JSON schema for the Tauri config.
+  #[serde(rename = "$schema")]
+  pub schema: Option<String>,
+  /// App name, folded in from the old `package.productName`.
+  #[serde(alias = "product-name")]
+  pub product_name: Option<String>,
+  /// App version, folded in from the old `package.version`.
+  #[serde(default)]
+  pub version: Option<String>,
+  /// The application configuration.
+  #[serde(default)]
+  pub app: AppConfig,
+  /// The bundler configuration, hoisted out of the old `tauri` object.
+  #[serde(default)]
+  pub bundle: BundleConfig,
+  /// The build configuration.
+  #[serde(default)]
+  pub build: BuildConfig,
+  /// The plugins config. The updater configuration that used to live at `tauri.updater` is now
+  /// found here under the `updater` key; see [`PluginConfig::updater`].
+  #[serde(default)]
+  pub plugins: PluginConfig,
+}
+
+/// Extends [`PluginConfig`] with a typed accessor for the `updater` key, where [`migrate`] moves
+/// the old `tauri.updater` object.
+pub trait PluginConfigExt {
+  /// Deserializes the `updater` entry of this [`PluginConfig`] into an [`UpdaterConfig`], if
+  /// present and valid.
+  fn updater(&self) -> Option<UpdaterConfig>;
+}
+
+impl PluginConfigExt for PluginConfig {
+  fn updater(&self) -> Option<UpdaterConfig> {
+    serde_json::from_value(self.0.get("updater")?.clone()).ok()
+  }
+}
+
+/// Rewrites `value`, a JSON value parsed from a `tauri.conf.json` file, from the pre-RFC#5 shape
+/// (`package`/`tauri`/`tauri.bundle`/`tauri.updater`) into the [`Config`] shape in-memory, so that
+/// both old and new configuration files deserialize correctly. A value already in the new shape
+/// (no `package` or `tauri` object) is left untouched.
+pub fn migrate(value: &mut JsonValue) {
+  let Some(root) = value.as_object_mut() else {
+    return;
+  };
+
+  if !root.contains_key("package") && !root.contains_key("tauri") {
+    return;
+  }
+
+  if let Some(JsonValue::Object(package)) = root.remove("package") {
+    for key in ["productName", "product-name", "version"] {
+      if let Some(value) = package.get(key) {
+        root.entry(key.to_string()).or_insert_with(|| value.clone());
+      }
+    }
+  }
+
+  if let Some(JsonValue::Object(mut tauri)) = root.remove("tauri") {
+    if let Some(bundle) = tauri.remove("bundle") {
+      root.entry("bundle".to_string()).or_insert(bundle);
+    }
+
+    if let Some(updater) = tauri.remove("updater") {
+      let plugins = root
+        .entry("plugins".to_string())
+        .or_insert_with(|| JsonValue::Object(Default::default()));
+      if let JsonValue::Object(plugins) = plugins {
+        plugins.entry("updater".to_string()).or_insert(updater);
+      }
+    }
+
+    root.insert("app".to_string(), JsonValue::Object(tauri));
+  }
+
+  if let Some(JsonValue::Object(build)) = root.get_mut("build") {
+    if let Some(dev_path) = build.remove("devPath") {
+      build.entry("devUrl".to_string()).or_insert(dev_path);
+    }
+    if let Some(dist_dir) = build.remove("distDir") {
+      build.entry("frontendDist".to_string()).or_insert(dist_dir);
+    }
+  }
+}
+
+/// The real RFC#5 parse entry point: parses `path` exactly the way
+/// [`crate::config_v1::parse::parse_value_with_override`] does (base file, merged with its
+/// platform overlay, then a JSON-string `--config`/`TAURI_CONFIG` override), runs [`migrate`] on
+/// the result, and deserializes into this module's [`Config`] rather than
+/// [`crate::config_v1::Config`]. A pre-RFC#5 `tauri.conf.json` and a post-RFC#5 one both end up
+/// here, which is what makes [`migrate`] load-bearing instead of dead code.
+pub fn parse_value_with_override(
+  path: impl Into<std::path::PathBuf>,
+  inline_config: Option<&str>,
+) -> Result<(Config, Vec<std::path::PathBuf>), crate::config_v1::parse::ConfigError> {
+  use crate::config_v1::parse::ConfigError;
+
+  let (mut value, contributing) =
+    crate::config_v1::parse::parse_value_with_override(path, inline_config)?;
+
+  migrate(&mut value);
+
+  let config = serde_json::from_value(value).map_err(|error| ConfigError::FormatJson {
+    path: contributing[0].clone(),
+    error,
+  })?;
+
+  Ok((config, contributing))
+}
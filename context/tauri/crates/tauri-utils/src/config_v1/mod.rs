@@ -4,7 +4,11 @@
 
 //! The Tauri configuration used at runtime.
 //!
-//! It is pulled from a `tauri.conf.json` file and the [`Config`] struct is generated at compile time.
+//! It is pulled from a `tauri.conf.json` file (or, with the `config-json5`/`config-toml` Cargo
+//! features enabled, a `tauri.conf.json5`/`Tauri.toml` file) and the [`Config`] struct is
+//! generated at compile time. When more than one of these files is present, `tauri.conf.json`
+//! takes precedence, followed by `tauri.conf.json5`, then `Tauri.toml`. See [`parse`] for the
+//! file lookup and format-detection logic.
 //!
 //! # Stability
 //! This is a core functionality that is not considered part of the stable API.
@@ -19,11 +23,13 @@ use serde_json::Value as JsonValue;
 use serde_with::skip_serializing_none;
 use url::Url;
 
+use crate::acl::Target;
+
 use std::{
   collections::HashMap,
   fmt::{self, Display},
   fs::read_to_string,
-  path::PathBuf,
+  path::{Path, PathBuf},
   str::FromStr,
 };
 
@@ -82,6 +88,8 @@ pub enum BundleType {
   Dmg,
   /// The Tauri updater bundle.
   Updater,
+  /// The RPM bundle (.rpm).
+  Rpm,
 }
 
 impl Display for BundleType {
@@ -97,6 +105,7 @@ impl Display for BundleType {
         Self::App => "app",
         Self::Dmg => "dmg",
         Self::Updater => "updater",
+        Self::Rpm => "rpm",
       }
     )
   }
@@ -125,6 +134,7 @@ impl<'de> Deserialize<'de> for BundleType {
       "app" => Ok(Self::App),
       "dmg" => Ok(Self::Dmg),
       "updater" => Ok(Self::Updater),
+      "rpm" => Ok(Self::Rpm),
       _ => Err(DeError::custom(format!("unknown bundle target '{s}'"))),
     }
   }
@@ -311,27 +321,115 @@ pub struct AppImageConfig {
 #[skip_serializing_none]
 #[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
 pub struct DebConfig {
   /// The list of deb dependencies your application relies on.
+  #[cfg_attr(feature = "clap", arg(long = "deb-depends"))]
   pub depends: Option<Vec<String>>,
   /// The files to include on the package.
   #[serde(default)]
+  #[cfg_attr(feature = "clap", arg(skip))]
   pub files: HashMap<PathBuf, PathBuf>,
   /// Path to a custom desktop file Handlebars template.
   ///
   /// Available variables: `categories`, `comment` (optional), `exec`, `icon` and `name`.
+  #[cfg_attr(feature = "clap", arg(long = "deb-desktop-template"))]
   pub desktop_template: Option<PathBuf>,
   /// Define the section in Debian Control file. See : https://www.debian.org/doc/debian-policy/ch-archive.html#s-subsections
+  #[cfg_attr(feature = "clap", arg(long = "deb-section"))]
   pub section: Option<String>,
   /// Change the priority of the Debian Package. By default, it is set to `optional`.
   /// Recognized Priorities as of now are :  `required`, `important`, `standard`, `optional`, `extra`
+  #[cfg_attr(feature = "clap", arg(long = "deb-priority"))]
   pub priority: Option<String>,
-  /// Path of the uncompressed Changelog file, to be stored at /usr/share/doc/package-name/changelog.gz. See
+  /// Path of the uncompressed Changelog file. It is compressed and stored at
+  /// /usr/share/doc/package-name/changelog.Debian.gz. See
   /// https://www.debian.org/doc/debian-policy/ch-docs.html#changelog-files-and-release-notes
+  #[cfg_attr(feature = "clap", arg(long = "deb-changelog"))]
   pub changelog: Option<PathBuf>,
 }
 
+impl DebConfig {
+  /// Sets the list of deb dependencies.
+  pub fn depends(mut self, depends: impl Into<Option<Vec<String>>>) -> Self {
+    self.depends = depends.into();
+    self
+  }
+
+  /// Sets the files to include on the package.
+  pub fn files(mut self, files: HashMap<PathBuf, PathBuf>) -> Self {
+    self.files = files;
+    self
+  }
+
+  /// Sets the path to a custom desktop file Handlebars template.
+  pub fn desktop_template(mut self, desktop_template: impl Into<Option<PathBuf>>) -> Self {
+    self.desktop_template = desktop_template.into();
+    self
+  }
+
+  /// Sets the Debian Control file section.
+  pub fn section(mut self, section: impl Into<Option<String>>) -> Self {
+    self.section = section.into();
+    self
+  }
+
+  /// Sets the Debian package priority.
+  pub fn priority(mut self, priority: impl Into<Option<String>>) -> Self {
+    self.priority = priority.into();
+    self
+  }
+
+  /// Sets the path of the uncompressed Changelog file.
+  pub fn changelog(mut self, changelog: impl Into<Option<PathBuf>>) -> Self {
+    self.changelog = changelog.into();
+    self
+  }
+}
+
+fn default_rpm_release() -> String {
+  "1".into()
+}
+
+/// Configuration for RPM (.rpm) bundles.
+///
+/// See more: https://tauri.app/v1/api/config#rpmconfig
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RpmConfig {
+  /// The list of RPM dependencies your application relies on.
+  pub depends: Option<Vec<String>>,
+  /// The RPM release tag.
+  #[serde(default = "default_rpm_release")]
+  pub release: String,
+  /// The RPM epoch.
+  #[serde(default)]
+  pub epoch: u32,
+  /// The files to include on the package.
+  #[serde(default)]
+  pub files: HashMap<PathBuf, PathBuf>,
+  /// Path to a custom desktop file Handlebars template.
+  ///
+  /// Available variables: `categories`, `comment` (optional), `exec`, `icon` and `name`.
+  pub desktop_template: Option<PathBuf>,
+}
+
+impl Default for RpmConfig {
+  fn default() -> Self {
+    Self {
+      depends: None,
+      release: default_rpm_release(),
+      epoch: 0,
+      files: Default::default(),
+      desktop_template: None,
+    }
+  }
+}
+
 fn de_minimum_system_version<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
   D: Deserializer<'de>,
@@ -349,11 +447,14 @@ where
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
 pub struct MacConfig {
   /// A list of strings indicating any macOS X frameworks that need to be bundled with the application.
   ///
   /// If a name is used, ".framework" must be omitted and it will look for standard install locations. You may also use a path to a specific framework.
+  #[cfg_attr(feature = "clap", arg(long = "macos-frameworks"))]
   pub frameworks: Option<Vec<String>>,
   /// A version string indicating the minimum macOS X version that the bundled application supports. Defaults to `10.13`.
   ///
@@ -366,20 +467,26 @@ pub struct MacConfig {
     default = "minimum_system_version",
     alias = "minimum-system-version"
   )]
+  #[cfg_attr(feature = "clap", arg(long = "macos-minimum-system-version"))]
   pub minimum_system_version: Option<String>,
   /// Allows your application to communicate with the outside world.
   /// It should be a lowercase, without port and protocol domain name.
   #[serde(alias = "exception-domain")]
+  #[cfg_attr(feature = "clap", arg(long = "macos-exception-domain"))]
   pub exception_domain: Option<String>,
   /// The path to the license file to add to the DMG bundle.
+  #[cfg_attr(feature = "clap", arg(long = "macos-license"))]
   pub license: Option<String>,
   /// Identity to use for code signing.
   #[serde(alias = "signing-identity")]
+  #[cfg_attr(feature = "clap", arg(long = "macos-signing-identity"))]
   pub signing_identity: Option<String>,
   /// Provider short name for notarization.
   #[serde(alias = "provider-short-name")]
+  #[cfg_attr(feature = "clap", arg(long = "macos-provider-short-name"))]
   pub provider_short_name: Option<String>,
   /// Path to the entitlements file.
+  #[cfg_attr(feature = "clap", arg(long = "macos-entitlements"))]
   pub entitlements: Option<String>,
 }
 
@@ -401,6 +508,106 @@ fn minimum_system_version() -> Option<String> {
   Some("10.13".into())
 }
 
+impl MacConfig {
+  /// Sets the macOS frameworks to bundle with the application.
+  pub fn frameworks(mut self, frameworks: impl Into<Option<Vec<String>>>) -> Self {
+    self.frameworks = frameworks.into();
+    self
+  }
+
+  /// Sets the minimum macOS version that the bundled application supports.
+  pub fn minimum_system_version(mut self, minimum_system_version: impl Into<Option<String>>) -> Self {
+    self.minimum_system_version = minimum_system_version.into();
+    self
+  }
+
+  /// Sets the exception domain used to communicate with the outside world.
+  pub fn exception_domain(mut self, exception_domain: impl Into<Option<String>>) -> Self {
+    self.exception_domain = exception_domain.into();
+    self
+  }
+
+  /// Sets the path to the license file to add to the DMG bundle.
+  pub fn license(mut self, license: impl Into<Option<String>>) -> Self {
+    self.license = license.into();
+    self
+  }
+
+  /// Sets the identity to use for code signing.
+  pub fn signing_identity(mut self, signing_identity: impl Into<Option<String>>) -> Self {
+    self.signing_identity = signing_identity.into();
+    self
+  }
+
+  /// Sets the provider short name for notarization.
+  pub fn provider_short_name(mut self, provider_short_name: impl Into<Option<String>>) -> Self {
+    self.provider_short_name = provider_short_name.into();
+    self
+  }
+
+  /// Sets the path to the entitlements file.
+  pub fn entitlements(mut self, entitlements: impl Into<Option<String>>) -> Self {
+    self.entitlements = entitlements.into();
+    self
+  }
+}
+
+/// A URL scheme to register the application as a handler for (deep-linking).
+///
+/// See more: https://tauri.app/v1/api/config#deeplinkprotocol
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DeepLinkProtocol {
+  /// The URL schemes the application should be registered to open, without the `://` suffix (e.g. `myapp`).
+  pub schemes: Vec<String>,
+  /// The `CFBundleTypeRole` to use on macOS for this scheme. Defaults to the OS default (`Viewer`).
+  pub role: Option<String>,
+}
+
+/// The role an application plays for a registered file type, mapped to `CFBundleTypeRole` on macOS.
+///
+/// See more: https://developer.apple.com/documentation/bundleresources/information_property_list/cfbundledocumenttypes/cfbundletyperole
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FileAssociationRole {
+  /// The app can view and edit documents of this type.
+  Editor,
+  /// The app can view documents of this type, but not edit them.
+  Viewer,
+  /// The app is a helper shell script invoked for documents of this type.
+  Shell,
+  /// No role, the app is not registered as a handler for this type.
+  None,
+}
+
+impl Default for FileAssociationRole {
+  fn default() -> Self {
+    Self::Editor
+  }
+}
+
+/// A file extension association the app should register itself as a handler for.
+///
+/// See more: https://tauri.app/v1/api/config#fileassociation
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FileAssociation {
+  /// File extensions to associate with this app, without the leading dot (e.g. `png`).
+  pub ext: Vec<String>,
+  /// The name that is displayed for this file type, e.g. "PNG Image".
+  pub name: Option<String>,
+  /// A description of the file type.
+  pub description: Option<String>,
+  /// The MIME type of the file, used on Linux to register a `x-scheme-handler/` style entry.
+  pub mime_type: Option<String>,
+  /// The app's role for this file type, mapped to `CFBundleTypeRole` on macOS.
+  #[serde(default)]
+  pub role: FileAssociationRole,
+}
+
 /// Configuration for a target language for the WiX build.
 ///
 /// See more: https://tauri.app/v1/api/config#wixlanguageconfig
@@ -437,62 +644,158 @@ impl Default for WixLanguage {
 /// See more: https://tauri.app/v1/api/config#wixconfig
 #[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
 pub struct WixConfig {
   /// The installer languages to build. See <https://docs.microsoft.com/en-us/windows/win32/msi/localizing-the-error-and-actiontext-tables>.
   #[serde(default)]
+  #[cfg_attr(feature = "clap", arg(skip))]
   pub language: WixLanguage,
   /// A custom .wxs template to use.
+  #[cfg_attr(feature = "clap", arg(long = "wix-template"))]
   pub template: Option<PathBuf>,
   /// A list of paths to .wxs files with WiX fragments to use.
   #[serde(default, alias = "fragment-paths")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-fragment-paths"))]
   pub fragment_paths: Vec<PathBuf>,
   /// The ComponentGroup element ids you want to reference from the fragments.
   #[serde(default, alias = "component-group-refs")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-component-group-refs"))]
   pub component_group_refs: Vec<String>,
   /// The Component element ids you want to reference from the fragments.
   #[serde(default, alias = "component-refs")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-component-refs"))]
   pub component_refs: Vec<String>,
   /// The FeatureGroup element ids you want to reference from the fragments.
   #[serde(default, alias = "feature-group-refs")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-feature-group-refs"))]
   pub feature_group_refs: Vec<String>,
   /// The Feature element ids you want to reference from the fragments.
   #[serde(default, alias = "feature-refs")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-feature-refs"))]
   pub feature_refs: Vec<String>,
   /// The Merge element ids you want to reference from the fragments.
   #[serde(default, alias = "merge-refs")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-merge-refs"))]
   pub merge_refs: Vec<String>,
   /// Disables the Webview2 runtime installation after app install.
   ///
   /// Will be removed in v2, prefer the [`WindowsConfig::webview_install_mode`] option.
   #[serde(default, alias = "skip-webview-install")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-skip-webview-install"))]
   pub skip_webview_install: bool,
   /// The path to the license file to render on the installer.
   ///
   /// Must be an RTF file, so if a different extension is provided, we convert it to the RTF format.
+  #[cfg_attr(feature = "clap", arg(long = "wix-license"))]
   pub license: Option<PathBuf>,
   /// Create an elevated update task within Windows Task Scheduler.
   #[serde(default, alias = "enable-elevated-update-task")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-enable-elevated-update-task"))]
   pub enable_elevated_update_task: bool,
   /// Path to a bitmap file to use as the installation user interface banner.
   /// This bitmap will appear at the top of all but the first page of the installer.
   ///
   /// The required dimensions are 493px × 58px.
   #[serde(alias = "banner-path")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-banner-path"))]
   pub banner_path: Option<PathBuf>,
   /// Path to a bitmap file to use on the installation user interface dialogs.
   /// It is used on the welcome and completion dialogs.
 
   /// The required dimensions are 493px × 312px.
   #[serde(alias = "dialog-image-path")]
+  #[cfg_attr(feature = "clap", arg(long = "wix-dialog-image-path"))]
   pub dialog_image_path: Option<PathBuf>,
 }
 
+impl WixConfig {
+  /// Sets the installer languages to build.
+  pub fn language(mut self, language: WixLanguage) -> Self {
+    self.language = language;
+    self
+  }
+
+  /// Sets a custom .wxs template to use.
+  pub fn template(mut self, template: impl Into<Option<PathBuf>>) -> Self {
+    self.template = template.into();
+    self
+  }
+
+  /// Sets the list of paths to .wxs files with WiX fragments to use.
+  pub fn fragment_paths(mut self, fragment_paths: Vec<PathBuf>) -> Self {
+    self.fragment_paths = fragment_paths;
+    self
+  }
+
+  /// Sets the ComponentGroup element ids to reference from the fragments.
+  pub fn component_group_refs(mut self, component_group_refs: Vec<String>) -> Self {
+    self.component_group_refs = component_group_refs;
+    self
+  }
+
+  /// Sets the Component element ids to reference from the fragments.
+  pub fn component_refs(mut self, component_refs: Vec<String>) -> Self {
+    self.component_refs = component_refs;
+    self
+  }
+
+  /// Sets the FeatureGroup element ids to reference from the fragments.
+  pub fn feature_group_refs(mut self, feature_group_refs: Vec<String>) -> Self {
+    self.feature_group_refs = feature_group_refs;
+    self
+  }
+
+  /// Sets the Feature element ids to reference from the fragments.
+  pub fn feature_refs(mut self, feature_refs: Vec<String>) -> Self {
+    self.feature_refs = feature_refs;
+    self
+  }
+
+  /// Sets the Merge element ids to reference from the fragments.
+  pub fn merge_refs(mut self, merge_refs: Vec<String>) -> Self {
+    self.merge_refs = merge_refs;
+    self
+  }
+
+  /// Sets whether to skip the Webview2 runtime installation after app install.
+  pub fn skip_webview_install(mut self, skip_webview_install: bool) -> Self {
+    self.skip_webview_install = skip_webview_install;
+    self
+  }
+
+  /// Sets the path to the license file to render on the installer.
+  pub fn license(mut self, license: impl Into<Option<PathBuf>>) -> Self {
+    self.license = license.into();
+    self
+  }
+
+  /// Sets whether to create an elevated update task within Windows Task Scheduler.
+  pub fn enable_elevated_update_task(mut self, enable_elevated_update_task: bool) -> Self {
+    self.enable_elevated_update_task = enable_elevated_update_task;
+    self
+  }
+
+  /// Sets the path to a bitmap file to use as the installation user interface banner.
+  pub fn banner_path(mut self, banner_path: impl Into<Option<PathBuf>>) -> Self {
+    self.banner_path = banner_path.into();
+    self
+  }
+
+  /// Sets the path to a bitmap file to use on the installation user interface dialogs.
+  pub fn dialog_image_path(mut self, dialog_image_path: impl Into<Option<PathBuf>>) -> Self {
+    self.dialog_image_path = dialog_image_path.into();
+    self
+  }
+}
+
 /// Compression algorithms used in the NSIS installer.
 ///
 /// See <https://nsis.sourceforge.io/Reference/SetCompressor>
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub enum NsisCompression {
   /// ZLIB uses the deflate algorithm, it is a quick and simple method. With the default compression level it uses about 300 KB of memory.
@@ -506,33 +809,48 @@ pub enum NsisCompression {
 /// Configuration for the Installer bundle using NSIS.
 #[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
 pub struct NsisConfig {
   /// A custom .nsi template to use.
+  #[cfg_attr(feature = "clap", arg(long = "nsis-template"))]
   pub template: Option<PathBuf>,
   /// The path to the license file to render on the installer.
+  #[cfg_attr(feature = "clap", arg(long = "nsis-license"))]
   pub license: Option<PathBuf>,
   /// The path to a bitmap file to display on the header of installers pages.
   ///
   /// The recommended dimensions are 150px x 57px.
   #[serde(alias = "header-image")]
+  #[cfg_attr(feature = "clap", arg(long = "nsis-header-image"))]
   pub header_image: Option<PathBuf>,
   /// The path to a bitmap file for the Welcome page and the Finish page.
   ///
   /// The recommended dimensions are 164px x 314px.
   #[serde(alias = "sidebar-image")]
+  #[cfg_attr(feature = "clap", arg(long = "nsis-sidebar-image"))]
   pub sidebar_image: Option<PathBuf>,
   /// The path to an icon file used as the installer icon.
   #[serde(alias = "install-icon")]
+  #[cfg_attr(feature = "clap", arg(long = "nsis-installer-icon"))]
   pub installer_icon: Option<PathBuf>,
   /// Whether the installation will be for all users or just the current user.
   #[serde(default, alias = "install-mode")]
+  #[cfg_attr(
+    feature = "clap",
+    arg(
+      long = "nsis-install-mode",
+      default_value_t = NSISInstallerMode::CurrentUser
+    )
+  )]
   pub install_mode: NSISInstallerMode,
   /// A list of installer languages.
   /// By default the OS language is used. If the OS language is not in the list of languages, the first language will be used.
   /// To allow the user to select the language, set `display_language_selector` to `true`.
   ///
   /// See <https://github.com/kichik/nsis/tree/9465c08046f00ccb6eda985abbdbf52c275c6c4d/Contrib/Language%20files> for the complete list of languages.
+  #[cfg_attr(feature = "clap", arg(long = "nsis-languages"))]
   pub languages: Option<Vec<String>>,
   /// A key-value pair where the key is the language and the
   /// value is the path to a custom `.nsh` file that holds the translated text for tauri's custom messages.
@@ -540,21 +858,90 @@ pub struct NsisConfig {
   /// See <https://github.com/tauri-apps/tauri/blob/dev/tooling/bundler/src/bundle/windows/templates/nsis-languages/English.nsh> for an example `.nsh` file.
   ///
   /// **Note**: the key must be a valid NSIS language and it must be added to [`NsisConfig`] languages array,
+  #[cfg_attr(feature = "clap", arg(skip))]
   pub custom_language_files: Option<HashMap<String, PathBuf>>,
   /// Whether to display a language selector dialog before the installer and uninstaller windows are rendered or not.
   /// By default the OS language is selected, with a fallback to the first language in the `languages` array.
   #[serde(default, alias = "display-language-selector")]
+  #[cfg_attr(feature = "clap", arg(long = "nsis-display-language-selector"))]
   pub display_language_selector: bool,
   /// Set the compression algorithm used to compress files in the installer.
   ///
   /// See <https://nsis.sourceforge.io/Reference/SetCompressor>
+  #[cfg_attr(feature = "clap", arg(long = "nsis-compression"))]
   pub compression: Option<NsisCompression>,
 }
 
+impl NsisConfig {
+  /// Sets a custom .nsi template to use.
+  pub fn template(mut self, template: impl Into<Option<PathBuf>>) -> Self {
+    self.template = template.into();
+    self
+  }
+
+  /// Sets the path to the license file to render on the installer.
+  pub fn license(mut self, license: impl Into<Option<PathBuf>>) -> Self {
+    self.license = license.into();
+    self
+  }
+
+  /// Sets the path to a bitmap file to display on the header of installers pages.
+  pub fn header_image(mut self, header_image: impl Into<Option<PathBuf>>) -> Self {
+    self.header_image = header_image.into();
+    self
+  }
+
+  /// Sets the path to a bitmap file for the Welcome page and the Finish page.
+  pub fn sidebar_image(mut self, sidebar_image: impl Into<Option<PathBuf>>) -> Self {
+    self.sidebar_image = sidebar_image.into();
+    self
+  }
+
+  /// Sets the path to an icon file used as the installer icon.
+  pub fn installer_icon(mut self, installer_icon: impl Into<Option<PathBuf>>) -> Self {
+    self.installer_icon = installer_icon.into();
+    self
+  }
+
+  /// Sets whether the installation will be for all users or just the current user.
+  pub fn install_mode(mut self, install_mode: NSISInstallerMode) -> Self {
+    self.install_mode = install_mode;
+    self
+  }
+
+  /// Sets the list of installer languages.
+  pub fn languages(mut self, languages: impl Into<Option<Vec<String>>>) -> Self {
+    self.languages = languages.into();
+    self
+  }
+
+  /// Sets the custom `.nsh` language files.
+  pub fn custom_language_files(
+    mut self,
+    custom_language_files: impl Into<Option<HashMap<String, PathBuf>>>,
+  ) -> Self {
+    self.custom_language_files = custom_language_files.into();
+    self
+  }
+
+  /// Sets whether to display a language selector dialog before the installer runs.
+  pub fn display_language_selector(mut self, display_language_selector: bool) -> Self {
+    self.display_language_selector = display_language_selector;
+    self
+  }
+
+  /// Sets the compression algorithm used to compress files in the installer.
+  pub fn compression(mut self, compression: impl Into<Option<NsisCompression>>) -> Self {
+    self.compression = compression.into();
+    self
+  }
+}
+
 /// Install Modes for the NSIS installer.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum NSISInstallerMode {
   /// Default mode for the installer.
   ///
@@ -581,6 +968,69 @@ impl Default for NSISInstallerMode {
   }
 }
 
+/// The UI level to use when running a WebView2 bootstrapper or offline installer.
+///
+/// Accepts a legacy boolean for backwards compatibility: `true` maps to [`Self::Silent`] and
+/// `false` maps to [`Self::WithUi`].
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum WebviewInstallerUiLevel {
+  /// Show the installer's full UI, requiring user interaction.
+  WithUi,
+  /// Show only a minimal progress bar, without prompting the user.
+  Passive,
+  /// Run the installer with no visible UI.
+  Silent,
+}
+
+impl Display for WebviewInstallerUiLevel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Self::WithUi => "withUi",
+        Self::Passive => "passive",
+        Self::Silent => "silent",
+      }
+    )
+  }
+}
+
+impl Default for WebviewInstallerUiLevel {
+  fn default() -> Self {
+    Self::Silent
+  }
+}
+
+impl<'de> Deserialize<'de> for WebviewInstallerUiLevel {
+  fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+      Bool(bool),
+      Str(String),
+    }
+
+    match Repr::deserialize(deserializer)? {
+      Repr::Bool(true) => Ok(Self::Silent),
+      Repr::Bool(false) => Ok(Self::WithUi),
+      Repr::Str(s) => match s.to_lowercase().as_str() {
+        "withui" => Ok(Self::WithUi),
+        "passive" => Ok(Self::Passive),
+        "silent" => Ok(Self::Silent),
+        _ => Err(DeError::custom(format!(
+          "unknown webview installer UI level '{s}'"
+        ))),
+      },
+    }
+  }
+}
+
 /// Install modes for the Webview2 runtime.
 /// Note that for the updater bundle [`Self::DownloadBootstrapper`] is used.
 ///
@@ -595,25 +1045,31 @@ pub enum WebviewInstallMode {
   /// Requires an internet connection.
   /// Results in a smaller installer size, but is not recommended on Windows 7.
   DownloadBootstrapper {
-    /// Instructs the installer to run the bootstrapper in silent mode. Defaults to `true`.
-    #[serde(default = "default_true")]
-    silent: bool,
+    /// Instructs the installer to run the bootstrapper silently, passively (progress bar only),
+    /// or with its full UI. Defaults to silent. Also accepts a boolean for backwards
+    /// compatibility, where `true` means silent.
+    #[serde(default)]
+    silent: WebviewInstallerUiLevel,
   },
   /// Embed the bootstrapper and run it.
   /// Requires an internet connection.
   /// Increases the installer size by around 1.8MB, but offers better support on Windows 7.
   EmbedBootstrapper {
-    /// Instructs the installer to run the bootstrapper in silent mode. Defaults to `true`.
-    #[serde(default = "default_true")]
-    silent: bool,
+    /// Instructs the installer to run the bootstrapper silently, passively (progress bar only),
+    /// or with its full UI. Defaults to silent. Also accepts a boolean for backwards
+    /// compatibility, where `true` means silent.
+    #[serde(default)]
+    silent: WebviewInstallerUiLevel,
   },
   /// Embed the offline installer and run it.
   /// Does not require an internet connection.
   /// Increases the installer size by around 127MB.
   OfflineInstaller {
-    /// Instructs the installer to run the installer in silent mode. Defaults to `true`.
-    #[serde(default = "default_true")]
-    silent: bool,
+    /// Instructs the installer to run the installer silently, passively (progress bar only), or
+    /// with its full UI. Defaults to silent. Also accepts a boolean for backwards compatibility,
+    /// where `true` means silent.
+    #[serde(default)]
+    silent: WebviewInstallerUiLevel,
   },
   /// Embed a fixed webview2 version and use it at runtime.
   /// Increases the installer size by around 180MB.
@@ -628,7 +1084,9 @@ pub enum WebviewInstallMode {
 
 impl Default for WebviewInstallMode {
   fn default() -> Self {
-    Self::DownloadBootstrapper { silent: true }
+    Self::DownloadBootstrapper {
+      silent: WebviewInstallerUiLevel::Silent,
+    }
   }
 }
 
@@ -637,25 +1095,36 @@ impl Default for WebviewInstallMode {
 /// See more: https://tauri.app/v1/api/config#windowsconfig
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
 pub struct WindowsConfig {
   /// Specifies the file digest algorithm to use for creating file signatures.
   /// Required for code signing. SHA-256 is recommended.
   #[serde(alias = "digest-algorithm")]
+  #[cfg_attr(feature = "clap", arg(long = "windows-digest-algorithm"))]
   pub digest_algorithm: Option<String>,
   /// Specifies the SHA1 hash of the signing certificate.
   #[serde(alias = "certificate-thumbprint")]
+  #[cfg_attr(feature = "clap", arg(long = "windows-certificate-thumbprint"))]
   pub certificate_thumbprint: Option<String>,
   /// Server to use during timestamping.
   #[serde(alias = "timestamp-url")]
+  #[cfg_attr(feature = "clap", arg(long = "windows-timestamp-url"))]
   pub timestamp_url: Option<String>,
   /// Whether to use Time-Stamp Protocol (TSP, a.k.a. RFC 3161) for the timestamp server. Your code signing provider may
   /// use a TSP timestamp server, like e.g. SSL.com does. If so, enable TSP by setting to true.
   #[serde(default)]
+  #[cfg_attr(feature = "clap", arg(long = "windows-tsp"))]
   pub tsp: bool,
   /// The installation mode for the Webview2 runtime.
   #[serde(default, alias = "webview-install-mode")]
+  #[cfg_attr(feature = "clap", arg(skip))]
   pub webview_install_mode: WebviewInstallMode,
+  /// Additional arguments given to the NSIS or WiX installer.
+  #[serde(default, alias = "installer-args")]
+  #[cfg_attr(feature = "clap", arg(long = "windows-installer-args"))]
+  pub installer_args: Vec<String>,
   /// Path to the webview fixed runtime to use. Overwrites [`Self::webview_install_mode`] if set.
   ///
   /// Will be removed in v2, prefer the [`Self::webview_install_mode`] option.
@@ -663,6 +1132,7 @@ pub struct WindowsConfig {
   /// The fixed version can be downloaded [on the official website](https://developer.microsoft.com/en-us/microsoft-edge/webview2/#download-section).
   /// The `.cab` file must be extracted to a folder and this folder path must be defined on this field.
   #[serde(alias = "webview-fixed-runtime-path")]
+  #[cfg_attr(feature = "clap", arg(long = "windows-webview-fixed-runtime-path"))]
   pub webview_fixed_runtime_path: Option<PathBuf>,
   /// Validates a second app installation, blocking the user from installing an older version if set to `false`.
   ///
@@ -670,10 +1140,13 @@ pub struct WindowsConfig {
   ///
   /// The default value of this flag is `true`.
   #[serde(default = "default_true", alias = "allow-downgrades")]
+  #[cfg_attr(feature = "clap", arg(long = "windows-allow-downgrades"))]
   pub allow_downgrades: bool,
   /// Configuration for the MSI generated with WiX.
+  #[cfg_attr(feature = "clap", command(flatten))]
   pub wix: Option<WixConfig>,
   /// Configuration for the installer generated with NSIS.
+  #[cfg_attr(feature = "clap", command(flatten))]
   pub nsis: Option<NsisConfig>,
 }
 
@@ -685,6 +1158,7 @@ impl Default for WindowsConfig {
       timestamp_url: None,
       tsp: false,
       webview_install_mode: Default::default(),
+      installer_args: Vec::new(),
       webview_fixed_runtime_path: None,
       allow_downgrades: true,
       wix: None,
@@ -693,6 +1167,71 @@ impl Default for WindowsConfig {
   }
 }
 
+impl WindowsConfig {
+  /// Sets the file digest algorithm to use for creating file signatures.
+  pub fn digest_algorithm(mut self, digest_algorithm: impl Into<Option<String>>) -> Self {
+    self.digest_algorithm = digest_algorithm.into();
+    self
+  }
+
+  /// Sets the SHA1 hash of the signing certificate.
+  pub fn certificate_thumbprint(mut self, certificate_thumbprint: impl Into<Option<String>>) -> Self {
+    self.certificate_thumbprint = certificate_thumbprint.into();
+    self
+  }
+
+  /// Sets the server to use during timestamping.
+  pub fn timestamp_url(mut self, timestamp_url: impl Into<Option<String>>) -> Self {
+    self.timestamp_url = timestamp_url.into();
+    self
+  }
+
+  /// Sets whether to use the Time-Stamp Protocol (TSP) for the timestamp server.
+  pub fn tsp(mut self, tsp: bool) -> Self {
+    self.tsp = tsp;
+    self
+  }
+
+  /// Sets the installation mode for the Webview2 runtime.
+  pub fn webview_install_mode(mut self, webview_install_mode: WebviewInstallMode) -> Self {
+    self.webview_install_mode = webview_install_mode;
+    self
+  }
+
+  /// Sets additional arguments given to the NSIS or WiX installer.
+  pub fn installer_args(mut self, installer_args: Vec<String>) -> Self {
+    self.installer_args = installer_args;
+    self
+  }
+
+  /// Sets the path to the webview fixed runtime to use.
+  pub fn webview_fixed_runtime_path(
+    mut self,
+    webview_fixed_runtime_path: impl Into<Option<PathBuf>>,
+  ) -> Self {
+    self.webview_fixed_runtime_path = webview_fixed_runtime_path.into();
+    self
+  }
+
+  /// Sets whether a second app installation should validate the currently installed version.
+  pub fn allow_downgrades(mut self, allow_downgrades: bool) -> Self {
+    self.allow_downgrades = allow_downgrades;
+    self
+  }
+
+  /// Sets the configuration for the MSI generated with WiX.
+  pub fn wix(mut self, wix: impl Into<Option<WixConfig>>) -> Self {
+    self.wix = wix.into();
+    self
+  }
+
+  /// Sets the configuration for the installer generated with NSIS.
+  pub fn nsis(mut self, nsis: impl Into<Option<NsisConfig>>) -> Self {
+    self.nsis = nsis.into();
+    self
+  }
+}
+
 /// Definition for bundle resources.
 /// Can be either a list of paths to include or a map of source to target paths.
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
@@ -754,6 +1293,9 @@ pub struct BundleConfig {
   /// Configuration for the Debian bundle.
   #[serde(default)]
   pub deb: DebConfig,
+  /// Configuration for the RPM bundle.
+  #[serde(default)]
+  pub rpm: RpmConfig,
   /// Configuration for the macOS bundles.
   #[serde(rename = "macOS", default)]
   pub macos: MacConfig,
@@ -773,6 +1315,27 @@ pub struct BundleConfig {
   /// Configuration for the Windows bundle.
   #[serde(default)]
   pub windows: WindowsConfig,
+  /// URL schemes the application should be registered to open (deep-linking).
+  pub deep_link_protocols: Option<Vec<DeepLinkProtocol>>,
+  /// File associations the application should register itself as a handler for.
+  pub file_associations: Option<Vec<FileAssociation>>,
+  /// A shell command to run once before the bundling phase starts, regardless of how many
+  /// targets are being packaged.
+  ///
+  /// The TAURI_ENV_TARGET_TRIPLE, TAURI_ENV_ARCH and TAURI_ENV_FAMILY environment variables are
+  /// set.
+  #[serde(alias = "before-packaging-command")]
+  pub before_packaging_command: Option<HookCommand>,
+  /// A shell command to run before each package format is built. It receives the same
+  /// environment variables as [`Self::before_packaging_command`] plus TAURI_ENV_PACKAGE_TYPE,
+  /// the short name of the package format (e.g. `nsis`, `deb`). The output path is not passed.
+  #[serde(alias = "before-each-package-command")]
+  pub before_each_package_command: Option<HookCommand>,
+  /// A shell command to run after each package format is built. It receives the same
+  /// environment variables as [`Self::before_packaging_command`] plus TAURI_ENV_PACKAGE_TYPE,
+  /// the short name of the package format (e.g. `nsis`, `deb`). The output path is not passed.
+  #[serde(alias = "after-each-package-command")]
+  pub after_each_package_command: Option<HookCommand>,
 }
 
 /// A CLI argument definition.
@@ -893,6 +1456,16 @@ pub struct CliArg {
   /// only the last positional argument may be defined as multiple (i.e. the one with the highest index).
   #[cfg_attr(feature = "schema", validate(range(min = 1)))]
   pub index: Option<usize>,
+  /// The default value of the argument, used when the argument is not provided at runtime.
+  #[serde(alias = "default-value")]
+  pub default_value: Option<String>,
+  /// Sets the default value of the argument with the signature `[arg, value, default]`, i.e. the
+  /// default is only used when `arg`'s value equals `value`.
+  #[serde(alias = "default-value-if")]
+  pub default_value_if: Option<Vec<String>>,
+  /// The environment variable to read the argument's value from when it is not provided at
+  /// runtime, checked before falling back to `default_value`.
+  pub env: Option<String>,
 }
 
 /// describes a CLI configuration
@@ -1032,6 +1605,17 @@ pub struct WindowConfig {
   /// If `true`, hides the window icon from the taskbar on Windows and Linux.
   #[serde(default, alias = "skip-taskbar")]
   pub skip_taskbar: bool,
+  /// If `true`, the window will be visible on all workspaces/spaces.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS:** Makes the window join every Space instead of only the one it was created on.
+  /// - **Linux:** Sets the `_NET_WM_STATE_STICKY` hint so the window manager keeps the window on
+  ///   every virtual desktop.
+  /// - **Windows:** Not supported, as Windows virtual desktops do not expose a per-window "pin to
+  ///   all desktops" API.
+  #[serde(default, alias = "visible-on-all-workspaces")]
+  pub visible_on_all_workspaces: bool,
   /// The initial window theme. Defaults to the system theme. Only implemented on Windows and macOS 10.14+.
   pub theme: Option<Theme>,
   /// The style of the macOS title bar.
@@ -1087,6 +1671,7 @@ impl Default for WindowConfig {
       always_on_top: false,
       content_protected: false,
       skip_taskbar: false,
+      visible_on_all_workspaces: false,
       theme: None,
       title_bar_style: Default::default(),
       hidden_title: false,
@@ -1113,8 +1698,79 @@ fn default_title() -> String {
   "Tauri App".to_string()
 }
 
+/// A hash algorithm used for CSP hash-based sources, e.g. `'sha256-<base64-digest>'`.
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Security-Policy/script-src#unsafe_hashes_usage>.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CspHashAlgorithm {
+  /// SHA-256.
+  Sha256,
+  /// SHA-384.
+  Sha384,
+  /// SHA-512.
+  Sha512,
+}
+
+impl CspHashAlgorithm {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Self::Sha256 => "sha256",
+      Self::Sha384 => "sha384",
+      Self::Sha512 => "sha512",
+    }
+  }
+}
+
+/// Whether a CSP source expression must be wrapped in single quotes as required by the spec.
+/// Covers the CSP keyword sources as well as `nonce-*` and `sha256-*`/`sha384-*`/`sha512-*` hash
+/// sources. Sources the caller already quoted are left untouched.
+fn csp_source_needs_quoting(source: &str) -> bool {
+  if source.starts_with('\'') {
+    return false;
+  }
+  matches!(
+    source,
+    "self"
+      | "unsafe-inline"
+      | "unsafe-eval"
+      | "unsafe-hashes"
+      | "none"
+      | "strict-dynamic"
+      | "report-sample"
+  ) || source.starts_with("nonce-")
+    || source.starts_with("sha256-")
+    || source.starts_with("sha384-")
+    || source.starts_with("sha512-")
+}
+
+fn quote_csp_source(source: &str) -> String {
+  if csp_source_needs_quoting(source) {
+    format!("'{source}'")
+  } else {
+    source.to_string()
+  }
+}
+
+/// Generates a fresh, per-response CSP nonce token.
+///
+/// `nonce-*` sources only block injected `<script>`/`<style>` tags if the nonce is
+/// attacker-unguessable, not merely unique, so this pulls 16 bytes straight from the OS CSPRNG
+/// rather than hashing observable/guessable inputs like the clock or PID.
+fn generate_csp_nonce() -> String {
+  use rand::RngCore;
+
+  let mut bytes = [0u8; 16];
+  rand::thread_rng().fill_bytes(&mut bytes);
+  bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
 /// A Content-Security-Policy directive source list.
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Security-Policy/Sources#sources>.
+///
+/// Keyword sources (e.g. `'self'`) as well as `nonce-*` and hash-based sources are stored
+/// unquoted and treated as opaque tokens; quoting is applied automatically when the policy is
+/// rendered to a string. Use [`Self::add_nonce`]/[`Self::add_hash`] to append them correctly.
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", untagged)]
@@ -1161,6 +1817,21 @@ impl CspDirectiveSources {
       }
     }
   }
+
+  /// Generates a fresh per-response nonce, appends it to this directive as a `nonce-<token>`
+  /// source, and returns the raw token so it can be stamped on the matching inline
+  /// `<script>`/`<style>` tag's `nonce` attribute.
+  pub fn add_nonce(&mut self) -> String {
+    let nonce = generate_csp_nonce();
+    self.push(format!("nonce-{nonce}"));
+    nonce
+  }
+
+  /// Appends a hash-based source for the given algorithm and base64-encoded digest, e.g.
+  /// `sha256-<base64-digest>`.
+  pub fn add_hash(&mut self, algorithm: CspHashAlgorithm, base64_digest: impl AsRef<str>) {
+    self.push(format!("{}-{}", algorithm.as_str(), base64_digest.as_ref()));
+  }
 }
 
 /// A Content-Security-Policy definition.
@@ -1209,6 +1880,7 @@ impl Display for Csp {
         let mut i = 0;
         for (directive, sources) in m {
           let sources: Vec<String> = sources.clone().into();
+          let sources: Vec<String> = sources.iter().map(|s| quote_csp_source(s)).collect();
           write!(f, "{} {}", directive, sources.join(" "))?;
           i += 1;
           if i != len {
@@ -1245,9 +1917,20 @@ impl Default for DisabledCspModificationKind {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct RemoteDomainAccessScope {
   /// The URL scheme to allow. By default, all schemas are allowed.
+  ///
+  /// Deprecated: use [`Self::url`] instead, which also lets you scope by subdomain and path.
   pub scheme: Option<String>,
   /// The domain to allow.
+  ///
+  /// Deprecated: use [`Self::url`] instead, which also lets you scope by subdomain and path.
   pub domain: String,
+  /// A URLPattern-style template matched against the full request URL, e.g.
+  /// `https://*.example.com/app/*`. Takes precedence over `scheme`/`domain` when set.
+  ///
+  /// Supports a `*` wildcard token within the protocol, hostname and pathname components (a
+  /// leading `*.` hostname label matches any single subdomain label), and `:name` named path
+  /// segments. Matching is anchored: the whole component must match, not just a substring.
+  pub url: Option<String>,
   /// The list of window labels this scope applies to.
   pub windows: Vec<String>,
   /// The list of plugins that are allowed in this scope.
@@ -1259,6 +1942,188 @@ pub struct RemoteDomainAccessScope {
   pub enable_tauri_api: bool,
 }
 
+impl RemoteDomainAccessScope {
+  /// Whether `url` is allowed by this scope's [`Self::url`] pattern (falling back to `https` when
+  /// the pattern omits a protocol component).
+  ///
+  /// Returns `false` if [`Self::url`] isn't set; callers should fall back to comparing
+  /// `scheme`/`domain` directly in that case.
+  pub fn matches(&self, url: &Url) -> bool {
+    let Some(pattern) = &self.url else {
+      return false;
+    };
+
+    let (pattern_scheme, rest) = pattern
+      .split_once("://")
+      .map(|(scheme, rest)| (Some(scheme), rest))
+      .unwrap_or((None, pattern.as_str()));
+    let (pattern_host, pattern_path) = rest.split_once('/').unwrap_or((rest, ""));
+
+    let scheme_matches = match_component(pattern_scheme.unwrap_or("https"), url.scheme());
+    let host_matches = url
+      .host_str()
+      .is_some_and(|host| match_hostname(pattern_host, host));
+    let path_matches = match_pathname(pattern_path, url.path());
+
+    scheme_matches && host_matches && path_matches
+  }
+}
+
+/// Matches a single, non-hostname URL component against a pattern that is either a `*` wildcard
+/// (matches one or more characters), a `:name` named segment (matches one or more characters), or
+/// literal text. Matching is case-insensitive and anchored over the whole component.
+fn match_component(pattern: &str, value: &str) -> bool {
+  if pattern == "*" || pattern.starts_with(':') {
+    return !value.is_empty();
+  }
+  pattern.eq_ignore_ascii_case(value)
+}
+
+/// Matches a hostname against a pattern, where a leading `*.` label matches exactly one
+/// subdomain label and a bare `*` matches the whole hostname. Hostname matching is
+/// case-insensitive.
+fn match_hostname(pattern: &str, host: &str) -> bool {
+  let pattern = pattern.to_ascii_lowercase();
+  let host = host.to_ascii_lowercase();
+
+  if pattern == "*" {
+    return true;
+  }
+
+  if let Some(suffix) = pattern.strip_prefix("*.") {
+    return match host.split_once('.') {
+      Some((label, rest)) => !label.is_empty() && rest == suffix,
+      None => false,
+    };
+  }
+
+  pattern == host
+}
+
+/// Matches a URL path against a pattern. An empty pattern only matches `/`. A pattern ending in
+/// `*` matches any path sharing its prefix; a `*` segment elsewhere matches exactly one non-empty
+/// path segment (the same "matches one or more characters" rule [`match_component`] applies to
+/// the scheme); `:name` named segments also match exactly one path segment; otherwise the path
+/// must match the pattern exactly.
+fn match_pathname(pattern: &str, path: &str) -> bool {
+  let pattern = format!("/{}", pattern.trim_start_matches('/'));
+
+  if pattern == "/" {
+    return path == "/";
+  }
+
+  if let Some(prefix) = pattern.strip_suffix('*') {
+    return path.starts_with(prefix);
+  }
+
+  let pattern_segments: Vec<&str> = pattern.split('/').collect();
+  let path_segments: Vec<&str> = path.split('/').collect();
+
+  pattern_segments.len() == path_segments.len()
+    && pattern_segments.iter().zip(path_segments.iter()).all(
+      |(pattern_segment, path_segment)| {
+        pattern_segment.starts_with(':')
+          || (*pattern_segment == "*" && !path_segment.is_empty())
+          || pattern_segment == path_segment
+      },
+    )
+}
+
+/// Configuration for CSP violation reporting.
+///
+/// When set, Tauri injects a `report-to`/`report-uri` directive pointing at an internal custom
+/// protocol endpoint. Violation reports POSTed by the webview to that endpoint are deserialized
+/// and forwarded to the app as a `tauri://csp-violation` event.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CspReportConfig {
+  /// Emit the policy as `Content-Security-Policy-Report-Only` instead of the enforcing
+  /// `Content-Security-Policy` header.
+  ///
+  /// Tauri still performs its usual nonce/hash injection (respecting
+  /// [`dangerous_disable_asset_csp_modification`](SecurityConfig::dangerous_disable_asset_csp_modification))
+  /// while in report-only mode, so flipping this back to `false` once the reports are clean
+  /// enforces the exact policy that was already being observed.
+  #[serde(default, alias = "report-only")]
+  pub report_only: bool,
+  /// Name of the reporting group violation reports are POSTed under.
+  ///
+  /// Included in the injected `report-to` directive automatically; only needs to be changed if
+  /// it collides with a reporting group the app defines itself.
+  #[serde(default = "default_csp_report_group")]
+  pub group: String,
+}
+
+impl Default for CspReportConfig {
+  fn default() -> Self {
+    Self {
+      report_only: false,
+      group: default_csp_report_group(),
+    }
+  }
+}
+
+fn default_csp_report_group() -> String {
+  "tauri-csp".into()
+}
+
+/// An allow/deny list of glob patterns restricting which paths the asset protocol may serve.
+///
+/// `deny` patterns always take precedence over `allow` patterns, so a directory can be exposed
+/// while carving out secrets within it.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct AssetProtocolScope {
+  /// Glob patterns that are allowed.
+  pub allow: Vec<String>,
+  /// Glob patterns that are denied. Always wins over `allow`.
+  pub deny: Vec<String>,
+}
+
+impl AssetProtocolScope {
+  /// Whether `path` is served by the asset protocol under this scope: it must match some
+  /// `allow` pattern and no `deny` pattern.
+  pub fn matches(&self, path: &str) -> bool {
+    if self.deny.iter().any(|pattern| fs_scope_glob_str_match(pattern, path)) {
+      return false;
+    }
+
+    self.allow.iter().any(|pattern| fs_scope_glob_str_match(pattern, path))
+  }
+}
+
+/// Matches `value` against a glob `pattern` supporting `*` (any sequence of characters) and `?`
+/// (any single character).
+fn fs_scope_glob_str_match(pattern: &str, value: &str) -> bool {
+  fn matches(p: &[u8], v: &[u8]) -> bool {
+    match p.first() {
+      None => v.is_empty(),
+      Some(b'*') => matches(&p[1..], v) || (!v.is_empty() && matches(p, &v[1..])),
+      Some(b'?') => !v.is_empty() && matches(&p[1..], &v[1..]),
+      Some(pc) => v.first() == Some(pc) && matches(&p[1..], &v[1..]),
+    }
+  }
+
+  matches(pattern.as_bytes(), value.as_bytes())
+}
+
+/// Configuration for the asset custom protocol, which serves local filesystem files directly to
+/// the webview.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AssetProtocolConfig {
+  /// Whether the asset protocol is enabled or not.
+  #[serde(default)]
+  pub enable: bool,
+  /// The access scope for the asset protocol.
+  #[serde(default)]
+  pub scope: AssetProtocolScope,
+}
+
 /// Security configuration.
 ///
 /// See more: https://tauri.app/v1/api/config#securityconfig
@@ -1315,14 +2180,30 @@ pub struct SecurityConfig {
   /// **WARNING:** Using a `http` scheme will allow mixed content when trying to fetch `http` endpoints and is therefore less secure but will match the behavior of the `<scheme>://localhost` protocols used on macOS and Linux.
   #[serde(default, alias = "dangerous-use-http-scheme")]
   pub dangerous_use_http_scheme: bool,
+  /// Configuration for CSP violation reporting, and/or switching the injected CSP to
+  /// `Content-Security-Policy-Report-Only` while tightening a new policy.
+  #[serde(default, alias = "csp-report")]
+  pub csp_report: Option<CspReportConfig>,
+  /// Configuration for the asset custom protocol.
+  ///
+  /// Supersedes `allowlist.protocol.assetScope`/`allowlist.protocol.asset`, which are still
+  /// parsed for backward compatibility but are deprecated in favor of this field.
+  #[serde(default, alias = "asset-protocol")]
+  pub asset_protocol: AssetProtocolConfig,
+  /// The capabilities that grant access to commands and their scopes, using the explicit,
+  /// opt-in permission system described in [`crate::acl`]. A command or scope entry is only
+  /// ever granted through an explicit `allow` rule in one of these capabilities, and any
+  /// matching `deny` rule always wins.
+  #[serde(default)]
+  pub capabilities: Vec<crate::acl::CapabilityEntry>,
 }
 
 /// Defines an allowlist type.
 pub trait Allowlist {
   /// Returns all features associated with the allowlist struct.
   fn all_features() -> Vec<&'static str>;
-  /// Returns the tauri features enabled on this allowlist.
-  fn to_features(&self) -> Vec<&'static str>;
+  /// Returns the tauri features enabled on this allowlist for the given `target`.
+  fn to_features(&self, target: Target) -> Vec<&'static str>;
 }
 
 macro_rules! check_feature {
@@ -1333,6 +2214,15 @@ macro_rules! check_feature {
   };
 }
 
+/// Whether an allowlist entry gated by `platforms` (`None` meaning all platforms) is enabled on
+/// `target`.
+fn platforms_allow(platforms: &Option<Vec<Target>>, target: Target) -> bool {
+  match platforms {
+    None => true,
+    Some(list) => list.contains(&target),
+  }
+}
+
 /// Filesystem scope definition.
 /// It is a list of glob patterns that restrict the API access from the webview.
 ///
@@ -1367,6 +2257,12 @@ pub enum FsAllowlistScope {
     // dotfiles are not supposed to be exposed by default on unix
     #[serde(alias = "require-literal-leading-dot")]
     require_literal_leading_dot: Option<bool>,
+    /// Whether symlinked targets are rejected outright, even if their real location would
+    /// otherwise be allowed.
+    ///
+    /// Defaults to `false`.
+    #[serde(default, alias = "forbid-symlinks")]
+    forbid_symlinks: Option<bool>,
   },
 }
 
@@ -1376,6 +2272,183 @@ impl Default for FsAllowlistScope {
   }
 }
 
+impl FsAllowlistScope {
+  fn allow(&self) -> &[PathBuf] {
+    match self {
+      Self::AllowedPaths(allow) => allow,
+      Self::Scope { allow, .. } => allow,
+    }
+  }
+
+  fn deny(&self) -> &[PathBuf] {
+    match self {
+      Self::AllowedPaths(_) => &[],
+      Self::Scope { deny, .. } => deny,
+    }
+  }
+
+  fn require_literal_leading_dot(&self) -> Option<bool> {
+    match self {
+      Self::AllowedPaths(_) => None,
+      Self::Scope {
+        require_literal_leading_dot,
+        ..
+      } => *require_literal_leading_dot,
+    }
+  }
+
+  fn forbid_symlinks(&self) -> bool {
+    match self {
+      Self::AllowedPaths(_) => false,
+      Self::Scope { forbid_symlinks, .. } => forbid_symlinks.unwrap_or(false),
+    }
+  }
+
+  /// Resolves whether `path` is allowed by this scope, defending against symlink escapes and
+  /// `..` traversal sequences.
+  ///
+  /// Unlike matching the raw, textual `path` against [`Self::allow`]/[`Self::deny`], this
+  /// canonicalizes `path` first (resolving symlinks and `..` components) and matches the glob
+  /// patterns against that canonical, real location. A path that textually looks like it is
+  /// inside an allowed directory but whose real location escapes it (via a symlink or `..`) is
+  /// therefore rejected, and `deny` keeps precedence over `allow` on the canonical form.
+  ///
+  /// Returns `false` if `path` does not exist, is a symlink and [`Self::forbid_symlinks`] is
+  /// enabled, or can't be canonicalized.
+  pub fn resolve(&self, path: &Path) -> bool {
+    if self.forbid_symlinks()
+      && path
+        .symlink_metadata()
+        .is_ok_and(|metadata| metadata.file_type().is_symlink())
+    {
+      return false;
+    }
+
+    let Ok(canonical) = path.canonicalize() else {
+      return false;
+    };
+
+    let require_literal_leading_dot = self
+      .require_literal_leading_dot()
+      .unwrap_or(cfg!(not(windows)));
+
+    let matches = |pattern: &PathBuf| {
+      fs_scope_glob_match(pattern, &canonical, require_literal_leading_dot)
+    };
+
+    if self.deny().iter().any(matches) {
+      return false;
+    }
+
+    self.allow().iter().any(matches)
+  }
+}
+
+/// Matches a canonicalized `path` against a glob `pattern`, supporting `*`/`?` wildcards and
+/// `[...]` character classes within each path component, plus a `**` component that matches zero
+/// or more intermediate path components. When `require_literal_leading_dot` is `true`, a `*`/`?`/
+/// `[...]` at the start of a component never matches a literal leading `.`.
+pub(crate) fn fs_scope_glob_match(
+  pattern: &Path,
+  path: &Path,
+  require_literal_leading_dot: bool,
+) -> bool {
+  fn class_contains(class: &[u8], value: u8) -> bool {
+    let mut i = 0;
+    while i < class.len() {
+      if i + 2 < class.len() && class[i + 1] == b'-' {
+        if (class[i]..=class[i + 2]).contains(&value) {
+          return true;
+        }
+        i += 3;
+      } else {
+        if class[i] == value {
+          return true;
+        }
+        i += 1;
+      }
+    }
+    false
+  }
+
+  fn component_matches(pattern: &[u8], value: &[u8], at_start: bool) -> bool {
+    match pattern.first() {
+      None => value.is_empty(),
+      Some(b'*') => {
+        if at_start && value.first() == Some(&b'.') {
+          return false;
+        }
+        component_matches(&pattern[1..], value, false)
+          || (!value.is_empty() && component_matches(pattern, &value[1..], false))
+      }
+      Some(b'?') => {
+        if at_start && value.first() == Some(&b'.') {
+          return false;
+        }
+        !value.is_empty() && component_matches(&pattern[1..], &value[1..], false)
+      }
+      Some(b'[') => {
+        if at_start && value.first() == Some(&b'.') {
+          return false;
+        }
+        let Some(value_head) = value.first().copied() else {
+          return false;
+        };
+        match pattern[1..].iter().position(|&b| b == b']') {
+          Some(rel_end) => {
+            let class_end = 1 + rel_end;
+            let mut class = &pattern[1..class_end];
+            let negate = matches!(class.first(), Some(b'!') | Some(b'^'));
+            if negate {
+              class = &class[1..];
+            }
+            (class_contains(class, value_head) != negate)
+              && component_matches(&pattern[class_end + 1..], &value[1..], false)
+          }
+          // No closing `]`: treat the `[` as a literal character.
+          None => value_head == b'[' && component_matches(&pattern[1..], &value[1..], false),
+        }
+      }
+      Some(pc) => value.first() == Some(pc) && component_matches(&pattern[1..], &value[1..], false),
+    }
+  }
+
+  fn components_match(
+    pattern: &[std::path::Component],
+    path: &[std::path::Component],
+    require_literal_leading_dot: bool,
+  ) -> bool {
+    match pattern.split_first() {
+      None => path.is_empty(),
+      Some((head, tail)) if head.as_os_str() == "**" => {
+        components_match(tail, path, require_literal_leading_dot)
+          || (!path.is_empty() && components_match(pattern, &path[1..], require_literal_leading_dot))
+      }
+      Some((head, tail)) => {
+        let Some((path_head, path_tail)) = path.split_first() else {
+          return false;
+        };
+        let pattern_str = head.as_os_str().to_string_lossy();
+        let path_str = path_head.as_os_str().to_string_lossy();
+        component_matches(
+          pattern_str.as_bytes(),
+          path_str.as_bytes(),
+          require_literal_leading_dot,
+        ) && components_match(tail, path_tail, require_literal_leading_dot)
+      }
+    }
+  }
+
+  let pattern_components: Vec<_> = pattern.components().collect();
+  let path_components: Vec<_> = path.components().collect();
+
+  components_match(
+    &pattern_components,
+    &path_components,
+    require_literal_leading_dot,
+  )
+}
+
 /// Allowlist for the file system APIs.
 ///
 /// See more: https://tauri.app/v1/api/config#fsallowlistconfig
@@ -1416,6 +2489,9 @@ pub struct FsAllowlistConfig {
   /// Check if path exists on the local filesystem.
   #[serde(default)]
   pub exists: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for FsAllowlistConfig {
@@ -1432,14 +2508,17 @@ impl Allowlist for FsAllowlistConfig {
       remove_file: true,
       rename_file: true,
       exists: true,
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("fs-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["fs-all"]
     } else {
       let mut features = Vec::new();
@@ -1566,6 +2645,9 @@ pub struct WindowAllowlistConfig {
   /// Allows opening the system dialog to print the window content.
   #[serde(default)]
   pub print: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for WindowAllowlistConfig {
@@ -1605,14 +2687,17 @@ impl Allowlist for WindowAllowlistConfig {
       set_ignore_cursor_events: true,
       start_dragging: true,
       print: true,
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("window-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["window-all"]
     } else {
       let mut features = Vec::new();
@@ -1707,6 +2792,26 @@ pub struct ShellAllowedCommand {
   #[serde(default)]
   pub args: ShellAllowedArgs,
 
+  /// The environment variables policy for the command execution.
+  ///
+  /// Defaults to stripping every environment variable from the spawned process. A value of
+  /// `true` passes through the whole parent environment, and a list of [`ShellAllowedEnv`]
+  /// restricts it to just those variables.
+  #[serde(default)]
+  pub env: ShellAllowedEnvVars,
+
+  /// The working directory the command is executed in.
+  ///
+  /// It can start with a variable that resolves to a system base directory.
+  /// The variables are: `$AUDIO`, `$CACHE`, `$CONFIG`, `$DATA`, `$LOCALDATA`, `$DESKTOP`,
+  /// `$DOCUMENT`, `$DOWNLOAD`, `$EXE`, `$FONT`, `$HOME`, `$PICTURE`, `$PUBLIC`, `$RUNTIME`,
+  /// `$TEMPLATE`, `$VIDEO`, `$RESOURCE`, `$APP`, `$LOG`, `$TEMP`, `$APPCONFIG`, `$APPDATA`,
+  /// `$APPLOCALDATA`, `$APPCACHE`, `$APPLOG`.
+  ///
+  /// Defaults to the calling process' current working directory.
+  #[serde(default)]
+  pub cwd: Option<PathBuf>,
+
   /// If this command is a sidecar command.
   #[serde(default)]
   pub sidecar: bool,
@@ -1725,24 +2830,83 @@ impl<'de> Deserialize<'de> for ShellAllowedCommand {
       #[serde(default)]
       args: ShellAllowedArgs,
       #[serde(default)]
+      env: ShellAllowedEnvVars,
+      #[serde(default)]
+      cwd: Option<PathBuf>,
+      #[serde(default)]
       sidecar: bool,
     }
 
-    let config = InnerShellAllowedCommand::deserialize(deserializer)?;
+    let config = InnerShellAllowedCommand::deserialize(deserializer)?;
+
+    if !config.sidecar && config.command.is_none() {
+      return Err(DeError::custom(
+        "The shell scope `command` value is required.",
+      ));
+    }
+
+    Ok(ShellAllowedCommand {
+      name: config.name,
+      command: config.command.unwrap_or_default(),
+      args: config.args,
+      env: config.env,
+      cwd: config.cwd,
+      sidecar: config.sidecar,
+    })
+  }
+}
+
+/// The environment variable policy for a [`ShellAllowedCommand`].
+///
+/// A value of `true` will allow the whole parent environment to be inherited by the spawned
+/// command. `false` will strip every environment variable. A list of [`ShellAllowedEnv`] will set
+/// those variables as the only ones passed to the command, with any variable not covered by the
+/// list stripped.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged, deny_unknown_fields)]
+#[non_exhaustive]
+pub enum ShellAllowedEnvVars {
+  /// Use a simple boolean to allow or strip the whole parent environment.
+  Flag(bool),
+
+  /// A specific set of [`ShellAllowedEnv`] that are passed to the command.
+  List(Vec<ShellAllowedEnv>),
+}
+
+impl Default for ShellAllowedEnvVars {
+  fn default() -> Self {
+    Self::Flag(false)
+  }
+}
+
+/// An environment variable allowed to be passed to a [`ShellAllowedCommand`].
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged, deny_unknown_fields)]
+#[non_exhaustive]
+pub enum ShellAllowedEnv {
+  /// A non-configurable environment variable baked into the command configuration.
+  Fixed {
+    /// The environment variable name.
+    name: String,
+    /// The environment variable value.
+    value: String,
+  },
 
-    if !config.sidecar && config.command.is_none() {
-      return Err(DeError::custom(
-        "The shell scope `command` value is required.",
-      ));
-    }
+  /// An environment variable that is set while calling the command from the webview API.
+  Var {
+    /// The environment variable name.
+    name: String,
 
-    Ok(ShellAllowedCommand {
-      name: config.name,
-      command: config.command.unwrap_or_default(),
-      args: config.args,
-      sidecar: config.sidecar,
-    })
-  }
+    /// [regex] validator to require passed values to conform to an expected input.
+    ///
+    /// This will require the value passed for this variable to match the `validator` regex
+    /// before it will be executed.
+    ///
+    /// [regex]: https://docs.rs/regex/latest/regex/#syntax
+    validator: String,
+  },
 }
 
 /// A set of command arguments allowed to be executed by the webview API.
@@ -1845,6 +3009,9 @@ pub struct ShellAllowlistConfig {
   /// Open URL with the user's default application.
   #[serde(default)]
   pub open: ShellAllowlistOpen,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for ShellAllowlistConfig {
@@ -1855,20 +3022,28 @@ impl Allowlist for ShellAllowlistConfig {
       execute: true,
       sidecar: true,
       open: ShellAllowlistOpen::Flag(true),
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("shell-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["shell-all"]
     } else {
       let mut features = Vec::new();
       check_feature!(self, features, execute, "shell-execute");
       check_feature!(self, features, sidecar, "shell-sidecar");
 
+      // a non-empty scope implicitly allows executing the commands it declares
+      if !self.scope.0.is_empty() && !features.contains(&"shell-execute") {
+        features.push("shell-execute");
+      }
+
       if !matches!(self.open, ShellAllowlistOpen::Flag(false)) {
         features.push("shell-open")
       }
@@ -1903,6 +3078,9 @@ pub struct DialogAllowlistConfig {
   /// Allows the API to show a dialog window with Ok/Cancel buttons.
   #[serde(default)]
   pub confirm: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for DialogAllowlistConfig {
@@ -1914,14 +3092,17 @@ impl Allowlist for DialogAllowlistConfig {
       message: true,
       ask: true,
       confirm: true,
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("dialog-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["dialog-all"]
     } else {
       let mut features = Vec::new();
@@ -1935,20 +3116,144 @@ impl Allowlist for DialogAllowlistConfig {
   }
 }
 
-/// HTTP API scope definition.
-/// It is a list of URLs that can be accessed by the webview when using the HTTP APIs.
-/// The scoped URL is matched against the request URL using a glob pattern.
+/// A single entry in an [`HttpAllowlistScope`]: a URL glob pattern, optionally restricted to a
+/// set of HTTP methods.
 ///
-/// Examples:
-/// - "https://*": allows all HTTPS urls
-/// - "https://*.github.com/tauri-apps/tauri": allows any subdomain of "github.com" with the "tauri-apps/api" path
-/// - "https://myapi.service.com/users/*": allows access to any URLs that begins with "https://myapi.service.com/users/"
+/// Can be deserialized from a bare URL string (equivalent to `{ "url": "..." }`, allowing any
+/// method) for backward compatibility with the plain `Vec<Url>` scope.
 #[allow(rustdoc::bare_urls)]
-#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
-// TODO: in v2, parse into a String or a custom type that perserves the
-// glob string because Url type will add a trailing slash
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HttpScopeEntry {
+  /// The URL glob pattern this entry matches against the request URL.
+  ///
+  /// Examples:
+  /// - "https://*": allows all HTTPS urls
+  /// - "https://*.github.com/tauri-apps/tauri": allows any subdomain of "github.com" with the "tauri-apps/tauri" path
+  /// - "https://myapi.service.com/users/*": allows access to any URLs that begins with "https://myapi.service.com/users/"
+  pub url: String,
+  /// The HTTP methods allowed for requests matching [`Self::url`]. `None` allows any method.
+  #[serde(default)]
+  pub methods: Option<Vec<String>>,
+}
+
+impl<'de> Deserialize<'de> for HttpScopeEntry {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(untagged, deny_unknown_fields)]
+    enum HttpScopeEntryRepr {
+      Url(String),
+      Entry {
+        url: String,
+        #[serde(default)]
+        methods: Option<Vec<String>>,
+      },
+    }
+
+    Ok(match HttpScopeEntryRepr::deserialize(deserializer)? {
+      HttpScopeEntryRepr::Url(url) => HttpScopeEntry { url, methods: None },
+      HttpScopeEntryRepr::Entry { url, methods } => HttpScopeEntry { url, methods },
+    })
+  }
+}
+
+/// HTTP API scope definition.
+///
+/// Accepts either a plain list of [`HttpScopeEntry`] (all treated as `allow`), or an object with
+/// explicit `allow`/`deny` lists. `deny` entries always take precedence over `allow` entries.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-pub struct HttpAllowlistScope(pub Vec<Url>);
+pub enum HttpAllowlistScope {
+  /// A plain list of entries that are allowed.
+  AllowedUrls(Vec<HttpScopeEntry>),
+  /// An explicit allow/deny list of entries.
+  Scope {
+    /// Entries that are allowed.
+    #[serde(default)]
+    allow: Vec<HttpScopeEntry>,
+    /// Entries that are denied. Always wins over `allow`.
+    #[serde(default)]
+    deny: Vec<HttpScopeEntry>,
+  },
+}
+
+impl Default for HttpAllowlistScope {
+  fn default() -> Self {
+    Self::AllowedUrls(Vec::new())
+  }
+}
+
+impl HttpAllowlistScope {
+  fn allow(&self) -> &[HttpScopeEntry] {
+    match self {
+      Self::AllowedUrls(allow) => allow,
+      Self::Scope { allow, .. } => allow,
+    }
+  }
+
+  fn deny(&self) -> &[HttpScopeEntry] {
+    match self {
+      Self::AllowedUrls(_) => &[],
+      Self::Scope { deny, .. } => deny,
+    }
+  }
+
+  /// Whether a request for `url` using `method` is permitted by this scope: `url` must match
+  /// some `allow` entry (whose `methods`, if set, must contain `method`) and no `deny` entry.
+  pub fn matches(&self, url: &str, method: &str) -> bool {
+    let entry_matches = |entry: &HttpScopeEntry| {
+      http_scope_glob_match(&entry.url, url)
+        && entry
+          .methods
+          .as_ref()
+          .is_none_or(|methods| methods.iter().any(|m| m.eq_ignore_ascii_case(method)))
+    };
+
+    if self.deny().iter().any(entry_matches) {
+      return false;
+    }
+
+    self.allow().iter().any(entry_matches)
+  }
+}
+
+/// Matches `value` against `pattern`, where `pattern` may contain any number of `*` wildcards,
+/// each matching zero or more characters. Also used by [`crate::acl`] to match window label
+/// patterns, so there is a single, correct implementation of this glob shared across the crate.
+pub(crate) fn http_scope_glob_match(pattern: &str, value: &str) -> bool {
+  let segments: Vec<&str> = pattern.split('*').collect();
+  if segments.len() == 1 {
+    return pattern == value;
+  }
+
+  let first = segments[0];
+  let last = segments[segments.len() - 1];
+  let middle = &segments[1..segments.len() - 1];
+
+  if value.len() < first.len() + last.len() || !value.starts_with(first) || !value.ends_with(last) {
+    return false;
+  }
+
+  // Search the remaining segments, in order and without overlapping, in the region between the
+  // fixed prefix and suffix.
+  let end = value.len() - last.len();
+  let mut cursor = first.len();
+  for segment in middle {
+    if segment.is_empty() {
+      continue;
+    }
+    match value[cursor..end].find(segment) {
+      Some(offset) => cursor += offset + segment.len(),
+      None => return false,
+    }
+  }
+
+  true
+}
 
 /// Allowlist for the HTTP APIs.
 ///
@@ -1966,6 +3271,9 @@ pub struct HttpAllowlistConfig {
   /// Allows making HTTP requests.
   #[serde(default)]
   pub request: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for HttpAllowlistConfig {
@@ -1974,14 +3282,17 @@ impl Allowlist for HttpAllowlistConfig {
       scope: Default::default(),
       all: false,
       request: true,
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("http-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["http-all"]
     } else {
       let mut features = Vec::new();
@@ -2001,18 +3312,26 @@ pub struct NotificationAllowlistConfig {
   /// Use this flag to enable all notification API features.
   #[serde(default)]
   pub all: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for NotificationAllowlistConfig {
   fn all_features() -> Vec<&'static str> {
-    let allowlist = Self { all: false };
-    let mut features = allowlist.to_features();
+    let allowlist = Self {
+      all: false,
+      platforms: None,
+    };
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("notification-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      vec![]
+    } else if self.all {
       vec!["notification-all"]
     } else {
       vec![]
@@ -2030,18 +3349,26 @@ pub struct GlobalShortcutAllowlistConfig {
   /// Use this flag to enable all global shortcut API features.
   #[serde(default)]
   pub all: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for GlobalShortcutAllowlistConfig {
   fn all_features() -> Vec<&'static str> {
-    let allowlist = Self { all: false };
-    let mut features = allowlist.to_features();
+    let allowlist = Self {
+      all: false,
+      platforms: None,
+    };
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("global-shortcut-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      vec![]
+    } else if self.all {
       vec!["global-shortcut-all"]
     } else {
       vec![]
@@ -2059,18 +3386,26 @@ pub struct OsAllowlistConfig {
   /// Use this flag to enable all OS API features.
   #[serde(default)]
   pub all: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for OsAllowlistConfig {
   fn all_features() -> Vec<&'static str> {
-    let allowlist = Self { all: false };
-    let mut features = allowlist.to_features();
+    let allowlist = Self {
+      all: false,
+      platforms: None,
+    };
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("os-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      vec![]
+    } else if self.all {
       vec!["os-all"]
     } else {
       vec![]
@@ -2088,18 +3423,26 @@ pub struct PathAllowlistConfig {
   /// Use this flag to enable all path API features.
   #[serde(default)]
   pub all: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for PathAllowlistConfig {
   fn all_features() -> Vec<&'static str> {
-    let allowlist = Self { all: false };
-    let mut features = allowlist.to_features();
+    let allowlist = Self {
+      all: false,
+      platforms: None,
+    };
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("path-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      vec![]
+    } else if self.all {
       vec!["path-all"]
     } else {
       vec![]
@@ -2115,14 +3458,22 @@ impl Allowlist for PathAllowlistConfig {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct ProtocolAllowlistConfig {
   /// The access scope for the asset protocol.
+  ///
+  /// Deprecated: use [`SecurityConfig::asset_protocol`]'s `scope` instead, which supports a
+  /// `deny` list.
   #[serde(default, alias = "asset-scope")]
   pub asset_scope: FsAllowlistScope,
   /// Use this flag to enable all custom protocols.
   #[serde(default)]
   pub all: bool,
   /// Enables the asset protocol.
+  ///
+  /// Deprecated: use [`SecurityConfig::asset_protocol`]'s `enable` instead.
   #[serde(default)]
   pub asset: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for ProtocolAllowlistConfig {
@@ -2131,14 +3482,17 @@ impl Allowlist for ProtocolAllowlistConfig {
       asset_scope: Default::default(),
       all: false,
       asset: true,
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("protocol-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["protocol-all"]
     } else {
       let mut features = Vec::new();
@@ -2174,6 +3528,9 @@ pub struct ProcessAllowlistConfig {
   /// Enables the exit API.
   #[serde(default)]
   pub exit: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for ProcessAllowlistConfig {
@@ -2183,14 +3540,17 @@ impl Allowlist for ProcessAllowlistConfig {
       relaunch: true,
       relaunch_dangerous_allow_symlink_macos: false,
       exit: true,
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("process-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["process-all"]
     } else {
       let mut features = Vec::new();
@@ -2223,6 +3583,9 @@ pub struct ClipboardAllowlistConfig {
   /// Enables the clipboard's `readText` API.
   #[serde(default, alias = "readText")]
   pub read_text: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for ClipboardAllowlistConfig {
@@ -2231,14 +3594,17 @@ impl Allowlist for ClipboardAllowlistConfig {
       all: false,
       write_text: true,
       read_text: true,
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("clipboard-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["clipboard-all"]
     } else {
       let mut features = Vec::new();
@@ -2265,6 +3631,9 @@ pub struct AppAllowlistConfig {
   /// Enables the app's `hide` API.
   #[serde(default)]
   pub hide: bool,
+  /// Platforms this allowlist entry is enabled on. Defaults to all platforms.
+  #[serde(default)]
+  pub platforms: Option<Vec<Target>>,
 }
 
 impl Allowlist for AppAllowlistConfig {
@@ -2273,14 +3642,17 @@ impl Allowlist for AppAllowlistConfig {
       all: false,
       show: true,
       hide: true,
+      platforms: None,
     };
-    let mut features = allowlist.to_features();
+    let mut features = allowlist.to_features(Target::Linux);
     features.push("app-all");
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
-    if self.all {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
+    if !platforms_allow(&self.platforms, target) {
+      Vec::new()
+    } else if self.all {
       vec!["app-all"]
     } else {
       let mut features = Vec::new();
@@ -2368,24 +3740,24 @@ impl Allowlist for AllowlistConfig {
     features
   }
 
-  fn to_features(&self) -> Vec<&'static str> {
+  fn to_features(&self, target: Target) -> Vec<&'static str> {
     if self.all {
       vec!["api-all"]
     } else {
       let mut features = Vec::new();
-      features.extend(self.fs.to_features());
-      features.extend(self.window.to_features());
-      features.extend(self.shell.to_features());
-      features.extend(self.dialog.to_features());
-      features.extend(self.http.to_features());
-      features.extend(self.notification.to_features());
-      features.extend(self.global_shortcut.to_features());
-      features.extend(self.os.to_features());
-      features.extend(self.path.to_features());
-      features.extend(self.protocol.to_features());
-      features.extend(self.process.to_features());
-      features.extend(self.clipboard.to_features());
-      features.extend(self.app.to_features());
+      features.extend(self.fs.to_features(target));
+      features.extend(self.window.to_features(target));
+      features.extend(self.shell.to_features(target));
+      features.extend(self.dialog.to_features(target));
+      features.extend(self.http.to_features(target));
+      features.extend(self.notification.to_features(target));
+      features.extend(self.global_shortcut.to_features(target));
+      features.extend(self.os.to_features(target));
+      features.extend(self.path.to_features(target));
+      features.extend(self.protocol.to_features(target));
+      features.extend(self.process.to_features(target));
+      features.extend(self.clipboard.to_features(target));
+      features.extend(self.app.to_features(target));
       features
     }
   }
@@ -2448,6 +3820,49 @@ pub struct TauriConfig {
   pub macos_private_api: bool,
 }
 
+impl TauriConfig {
+  /// Validates cross-references between this configuration's sections that can't be expressed
+  /// through the type system alone.
+  ///
+  /// Currently this only checks that every [`ShellAllowedCommand`] in
+  /// `allowlist.shell.scope` that sets `sidecar: true` names a binary declared in
+  /// [`BundleConfig::external_bin`]; everything else is validated at deserialization time.
+  pub fn validate(&self) -> Result<(), String> {
+    let external_bins: Vec<&str> = self
+      .bundle
+      .external_bin
+      .iter()
+      .flatten()
+      .map(String::as_str)
+      .collect();
+
+    for command in &self.allowlist.shell.scope.0 {
+      if command.sidecar && !external_bins.contains(&command.command.to_string_lossy().as_ref()) {
+        return Err(format!(
+          "shell scope `{}` is configured as a sidecar but `{}` is not declared in `bundle.externalBin`",
+          command.name,
+          command.command.display()
+        ));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Returns the tauri features enabled by [`Self::allowlist`] for `target`, plus `protocol-asset`
+  /// when [`SecurityConfig::asset_protocol`] is enabled (even if the deprecated
+  /// `allowlist.protocol.asset` flag isn't set).
+  pub fn to_features(&self, target: Target) -> Vec<&'static str> {
+    let mut features = self.allowlist.to_features(target);
+
+    if self.security.asset_protocol.enable && !features.contains(&"protocol-asset") {
+      features.push("protocol-asset");
+    }
+
+    features
+  }
+}
+
 /// A URL to an updater server.
 ///
 /// The URL must use the `https` scheme on production.
@@ -2661,15 +4076,22 @@ pub struct SystemTrayConfig {
 }
 
 /// Defines the URL or assets to embed in the application.
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[serde(untagged, deny_unknown_fields)]
+#[serde(untagged)]
 #[non_exhaustive]
 pub enum AppUrl {
   /// The app's external URL, or the path to the directory containing the app assets.
   Url(WindowUrl),
   /// An array of files to embed on the app.
   Files(Vec<PathBuf>),
+  /// A URL served through a registered custom protocol (e.g. `app://localhost`), rather than
+  /// `http(s)` or the `tauri`/`asset` app protocols.
+  ///
+  /// Pointing `devUrl`/`distDir` (or a window's `url`) at one of these tells the IPC layer to
+  /// treat that protocol's origin as trusted, the same way it already trusts the `tauri`/`asset`
+  /// origins, instead of treating it as an arbitrary external URL.
+  CustomProtocol(Url),
 }
 
 impl std::fmt::Display for AppUrl {
@@ -2677,6 +4099,35 @@ impl std::fmt::Display for AppUrl {
     match self {
       Self::Url(url) => write!(f, "{url}"),
       Self::Files(files) => write!(f, "{}", serde_json::to_string(files).unwrap()),
+      Self::CustomProtocol(url) => write!(f, "{url}"),
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for AppUrl {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AppUrlRepr {
+      Files(Vec<PathBuf>),
+      Str(String),
+    }
+
+    // `Url` only accepts absolute URLs, so a bare relative path (e.g. `../dist`) falls through to
+    // `WindowUrl::App` below. Among absolute URLs, only `http`/`https` are treated as external;
+    // every other scheme (e.g. `app://localhost`) is a registered custom protocol.
+    match AppUrlRepr::deserialize(deserializer)? {
+      AppUrlRepr::Files(files) => Ok(Self::Files(files)),
+      AppUrlRepr::Str(value) => match Url::parse(&value) {
+        Ok(url) if url.scheme() == "http" || url.scheme() == "https" => {
+          Ok(Self::Url(WindowUrl::External(url)))
+        }
+        Ok(url) => Ok(Self::CustomProtocol(url)),
+        Err(_) => Ok(Self::Url(WindowUrl::App(value.into()))),
+      },
     }
   }
 }
@@ -2697,6 +4148,11 @@ pub enum BeforeDevCommand {
     /// Whether `tauri dev` should wait for the command to finish or not. Defaults to `false`.
     #[serde(default)]
     wait: bool,
+    /// Environment variables to set for the command, merged on top of the `TAURI_PLATFORM`,
+    /// `TAURI_ARCH`, `TAURI_FAMILY`, `TAURI_PLATFORM_VERSION`, `TAURI_PLATFORM_TYPE` and
+    /// `TAURI_DEBUG` variables the runner already sets.
+    #[serde(default)]
+    env: Option<HashMap<String, String>>,
   },
 }
 
@@ -2713,6 +4169,11 @@ pub enum HookCommand {
     script: String,
     /// The current working directory.
     cwd: Option<String>,
+    /// Environment variables to set for the command, merged on top of the `TAURI_PLATFORM`,
+    /// `TAURI_ARCH`, `TAURI_FAMILY`, `TAURI_PLATFORM_VERSION`, `TAURI_PLATFORM_TYPE` and
+    /// `TAURI_DEBUG` variables the runner already sets.
+    #[serde(default)]
+    env: Option<HashMap<String, String>>,
   },
 }
 
@@ -2720,7 +4181,7 @@ pub enum HookCommand {
 ///
 /// See more: https://tauri.app/v1/api/config#buildconfig
 #[skip_serializing_none]
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct BuildConfig {
@@ -2733,8 +4194,9 @@ pub struct BuildConfig {
   ///
   /// See [vite](https://vitejs.dev/guide/), [Webpack DevServer](https://webpack.js.org/configuration/dev-server/) and [sirv](https://github.com/lukeed/sirv)
   /// for examples on how to set up a dev server.
-  #[serde(default = "default_dev_path", alias = "dev-path")]
-  pub dev_path: AppUrl,
+  ///
+  /// May be omitted if the app is only ever run against `dist_dir` (one of the two is required).
+  pub dev_path: Option<AppUrl>,
   /// The path to the application assets or URL to load in production.
   ///
   /// When a path relative to the configuration file is provided,
@@ -2746,27 +4208,26 @@ pub struct BuildConfig {
   ///
   /// When an URL is provided, the application won't have bundled assets
   /// and the application will load that URL by default.
-  #[serde(default = "default_dist_dir", alias = "dist-dir")]
-  pub dist_dir: AppUrl,
+  ///
+  /// May be omitted when the app is meant to always load `dev_path` at runtime (for example, a
+  /// live-reload setup that serves the frontend distribution directory directly instead of
+  /// embedding it; one of the two is required).
+  pub dist_dir: Option<AppUrl>,
   /// A shell command to run before `tauri dev` kicks in.
   ///
   /// The TAURI_PLATFORM, TAURI_ARCH, TAURI_FAMILY, TAURI_PLATFORM_VERSION, TAURI_PLATFORM_TYPE and TAURI_DEBUG environment variables are set if you perform conditional compilation.
-  #[serde(alias = "before-dev-command")]
   pub before_dev_command: Option<BeforeDevCommand>,
   /// A shell command to run before `tauri build` kicks in.
   ///
   /// The TAURI_PLATFORM, TAURI_ARCH, TAURI_FAMILY, TAURI_PLATFORM_VERSION, TAURI_PLATFORM_TYPE and TAURI_DEBUG environment variables are set if you perform conditional compilation.
-  #[serde(alias = "before-build-command")]
   pub before_build_command: Option<HookCommand>,
   /// A shell command to run before the bundling phase in `tauri build` kicks in.
   ///
   /// The TAURI_PLATFORM, TAURI_ARCH, TAURI_FAMILY, TAURI_PLATFORM_VERSION, TAURI_PLATFORM_TYPE and TAURI_DEBUG environment variables are set if you perform conditional compilation.
-  #[serde(alias = "before-bundle-command")]
   pub before_bundle_command: Option<HookCommand>,
   /// Features passed to `cargo` commands.
   pub features: Option<Vec<String>>,
   /// Whether we should inject the Tauri API on `window.__TAURI__` or not.
-  #[serde(default, alias = "with-global-tauri")]
   pub with_global_tauri: bool,
 }
 
@@ -2774,8 +4235,8 @@ impl Default for BuildConfig {
   fn default() -> Self {
     Self {
       runner: None,
-      dev_path: default_dev_path(),
-      dist_dir: default_dist_dir(),
+      dev_path: None,
+      dist_dir: None,
       before_dev_command: None,
       before_build_command: None,
       before_bundle_command: None,
@@ -2785,14 +4246,49 @@ impl Default for BuildConfig {
   }
 }
 
-fn default_dev_path() -> AppUrl {
-  AppUrl::Url(WindowUrl::External(
-    Url::parse("http://localhost:8080").unwrap(),
-  ))
-}
+impl<'de> Deserialize<'de> for BuildConfig {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    struct InnerBuildConfig {
+      runner: Option<String>,
+      #[serde(default, alias = "dev-path")]
+      dev_path: Option<AppUrl>,
+      #[serde(default, alias = "dist-dir")]
+      dist_dir: Option<AppUrl>,
+      #[serde(alias = "before-dev-command")]
+      before_dev_command: Option<BeforeDevCommand>,
+      #[serde(alias = "before-build-command")]
+      before_build_command: Option<HookCommand>,
+      #[serde(alias = "before-bundle-command")]
+      before_bundle_command: Option<HookCommand>,
+      features: Option<Vec<String>>,
+      #[serde(default, alias = "with-global-tauri")]
+      with_global_tauri: bool,
+    }
+
+    let config = InnerBuildConfig::deserialize(deserializer)?;
 
-fn default_dist_dir() -> AppUrl {
-  AppUrl::Url(WindowUrl::App("../dist".into()))
+    if config.dev_path.is_none() && config.dist_dir.is_none() {
+      return Err(DeError::custom(
+        "at least one of `build > devPath` or `build > distDir` must be set",
+      ));
+    }
+
+    Ok(BuildConfig {
+      runner: config.runner,
+      dev_path: config.dev_path,
+      dist_dir: config.dist_dir,
+      before_dev_command: config.before_dev_command,
+      before_build_command: config.before_build_command,
+      before_bundle_command: config.before_bundle_command,
+      features: config.features,
+      with_global_tauri: config.with_global_tauri,
+    })
+  }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -2977,8 +4473,8 @@ pub struct PluginConfig(pub HashMap<String, JsonValue>);
 fn default_build() -> BuildConfig {
   BuildConfig {
     runner: None,
-    dev_path: default_dev_path(),
-    dist_dir: default_dist_dir(),
+    dev_path: None,
+    dist_dir: None,
     before_dev_command: None,
     before_build_command: None,
     before_bundle_command: None,
@@ -2987,6 +4483,39 @@ fn default_build() -> BuildConfig {
   }
 }
 
+impl Config {
+  /// Merges `overlay` into this configuration's JSON representation and re-deserializes it, as
+  /// described in the [`Config` documentation](Config#platform-specific-configuration): the
+  /// platform-specific file (e.g. `tauri.linux.conf.json`) is merged with the base
+  /// `tauri.conf.json` via [`merge`] before the combined value is used.
+  pub fn merge(&mut self, overlay: JsonValue) -> Result<(), serde_json::Error> {
+    let mut base = serde_json::to_value(&*self)?;
+    merge(&mut base, overlay);
+    *self = serde_json::from_value(base)?;
+    Ok(())
+  }
+}
+
+/// Recursively merges `overlay` into `base` in place.
+///
+/// Two JSON objects are merged key by key, recursing into nested objects. Any other value
+/// (including arrays) in `overlay` replaces the corresponding value in `base` wholesale. A `null`
+/// in `overlay` removes the key from `base` entirely, rather than overwriting it with `null`.
+pub fn merge(base: &mut JsonValue, overlay: JsonValue) {
+  match (base, overlay) {
+    (JsonValue::Object(base), JsonValue::Object(overlay)) => {
+      for (key, value) in overlay {
+        if value.is_null() {
+          base.remove(&key);
+        } else {
+          merge(base.entry(key).or_insert(JsonValue::Null), value);
+        }
+      }
+    }
+    (base, overlay) => *base = overlay,
+  }
+}
+
 /// How the window title bar should be displayed on macOS.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -3108,8 +4637,6 @@ mod test {
     let t_config = TauriConfig::default();
     // get default build config
     let b_config = BuildConfig::default();
-    // get default dev path
-    let d_path = default_dev_path();
     // get default window
     let d_windows: Vec<WindowConfig> = vec![];
     // get default bundle
@@ -3134,9 +4661,15 @@ mod test {
         long_description: None,
         appimage: Default::default(),
         deb: Default::default(),
+        rpm: Default::default(),
         macos: Default::default(),
         external_bin: None,
         windows: Default::default(),
+        deep_link_protocols: None,
+        file_associations: None,
+        before_packaging_command: None,
+        before_each_package_command: None,
+        after_each_package_command: None,
       },
       cli: None,
       updater: UpdaterConfig {
@@ -3153,6 +4686,9 @@ mod test {
         dangerous_disable_asset_csp_modification: DisabledCspModificationKind::Flag(false),
         dangerous_remote_domain_ipc_access: Vec::new(),
         dangerous_use_http_scheme: false,
+        csp_report: None,
+        asset_protocol: AssetProtocolConfig::default(),
+        capabilities: Vec::new(),
       },
       allowlist: AllowlistConfig::default(),
       system_tray: None,
@@ -3162,10 +4698,8 @@ mod test {
     // create a build config
     let build = BuildConfig {
       runner: None,
-      dev_path: AppUrl::Url(WindowUrl::External(
-        Url::parse("http://localhost:8080").unwrap(),
-      )),
-      dist_dir: AppUrl::Url(WindowUrl::App("../dist".into())),
+      dev_path: None,
+      dist_dir: None,
       before_dev_command: None,
       before_build_command: None,
       before_bundle_command: None,
@@ -3178,12 +4712,212 @@ mod test {
     assert_eq!(b_config, build);
     assert_eq!(d_bundle, tauri.bundle);
     assert_eq!(d_updater, tauri.updater);
-    assert_eq!(
-      d_path,
-      AppUrl::Url(WindowUrl::External(
-        Url::parse("http://localhost:8080").unwrap()
-      ))
-    );
     assert_eq!(d_windows, tauri.windows);
   }
+
+  #[test]
+  fn http_allowlist_scope_bare_string_allows_any_method() {
+    let scope: HttpAllowlistScope =
+      serde_json::from_str(r#"["https://*.tauri.app"]"#).expect("bare string list should parse");
+    assert!(scope.matches("https://foo.tauri.app", "GET"));
+    assert!(scope.matches("https://foo.tauri.app", "POST"));
+    assert!(!scope.matches("https://evil.com", "GET"));
+  }
+
+  #[test]
+  fn http_allowlist_scope_method_mismatch_is_denied() {
+    let scope: HttpAllowlistScope = serde_json::from_str(
+      r#"{ "allow": [{ "url": "https://tauri.app/*", "methods": ["GET"] }] }"#,
+    )
+    .expect("scope with methods should parse");
+
+    assert!(scope.matches("https://tauri.app/foo", "GET"));
+    assert!(scope.matches("https://tauri.app/foo", "get"));
+    assert!(!scope.matches("https://tauri.app/foo", "POST"));
+  }
+
+  #[test]
+  fn http_allowlist_scope_deny_wins_over_allow() {
+    let scope: HttpAllowlistScope = serde_json::from_str(
+      r#"{
+        "allow": [{ "url": "https://*.tauri.app" }],
+        "deny": [{ "url": "https://evil.tauri.app" }]
+      }"#,
+    )
+    .expect("allow/deny scope should parse");
+
+    assert!(scope.matches("https://foo.tauri.app", "GET"));
+    assert!(!scope.matches("https://evil.tauri.app", "GET"));
+  }
+
+  #[test]
+  fn http_scope_glob_match_supports_multiple_wildcards() {
+    assert!(http_scope_glob_match(
+      "https://*.github.com/*",
+      "https://api.github.com/tauri-apps/tauri"
+    ));
+    assert!(!http_scope_glob_match(
+      "https://*.github.com/*",
+      "https://evil.com/tauri-apps/tauri"
+    ));
+    assert!(!http_scope_glob_match(
+      "https://*.github.com/*",
+      "https://api.github.com"
+    ));
+  }
+
+  fn fs_test_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("tauri-fs-scope-test-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("failed to create test dir");
+    dir
+  }
+
+  #[test]
+  fn fs_allowlist_scope_deny_wins_over_allow() {
+    let dir = fs_test_dir("deny-precedence");
+    std::fs::write(dir.join("allowed.txt"), b"").unwrap();
+    std::fs::write(dir.join("secret.txt"), b"").unwrap();
+
+    let scope = FsAllowlistScope::Scope {
+      allow: vec![dir.join("*")],
+      deny: vec![dir.join("secret.txt")],
+      require_literal_leading_dot: None,
+      forbid_symlinks: None,
+    };
+
+    assert!(scope.resolve(&dir.join("allowed.txt")));
+    assert!(!scope.resolve(&dir.join("secret.txt")));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn fs_allowlist_scope_bare_paths_allow_list() {
+    let dir = fs_test_dir("bare-paths");
+    std::fs::write(dir.join("a.txt"), b"").unwrap();
+    std::fs::write(dir.join("b.txt"), b"").unwrap();
+
+    let scope = FsAllowlistScope::AllowedPaths(vec![dir.join("a.txt")]);
+
+    assert!(scope.resolve(&dir.join("a.txt")));
+    assert!(!scope.resolve(&dir.join("b.txt")));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn fs_allowlist_scope_forbid_symlinks_rejects_symlinked_path() {
+    let dir = fs_test_dir("forbid-symlinks");
+    let real_dir = dir.join("real");
+    std::fs::create_dir_all(&real_dir).unwrap();
+    std::fs::write(real_dir.join("target.txt"), b"").unwrap();
+    let link = dir.join("link.txt");
+    std::os::unix::fs::symlink(real_dir.join("target.txt"), &link).unwrap();
+
+    let scope = FsAllowlistScope::Scope {
+      allow: vec![dir.join("**")],
+      deny: vec![],
+      require_literal_leading_dot: None,
+      forbid_symlinks: Some(true),
+    };
+
+    assert!(!scope.resolve(&link));
+    assert!(scope.resolve(&real_dir.join("target.txt")));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn fs_scope_glob_match_double_star_matches_arbitrarily_nested_paths() {
+    let pattern = Path::new("/data/**/file.txt");
+
+    assert!(fs_scope_glob_match(pattern, Path::new("/data/file.txt"), true));
+    assert!(fs_scope_glob_match(
+      pattern,
+      Path::new("/data/a/file.txt"),
+      true
+    ));
+    assert!(fs_scope_glob_match(
+      pattern,
+      Path::new("/data/a/b/c/file.txt"),
+      true
+    ));
+    assert!(!fs_scope_glob_match(
+      pattern,
+      Path::new("/data/a/b/other.txt"),
+      true
+    ));
+  }
+
+  #[test]
+  fn fs_scope_glob_match_character_class() {
+    assert!(fs_scope_glob_match(
+      Path::new("/data/file[0-9].txt"),
+      Path::new("/data/file3.txt"),
+      true
+    ));
+    assert!(!fs_scope_glob_match(
+      Path::new("/data/file[0-9].txt"),
+      Path::new("/data/fileA.txt"),
+      true
+    ));
+    assert!(fs_scope_glob_match(
+      Path::new("/data/file[!0-9].txt"),
+      Path::new("/data/fileA.txt"),
+      true
+    ));
+  }
+
+  fn remote_domain_scope(url: &str) -> RemoteDomainAccessScope {
+    RemoteDomainAccessScope {
+      scheme: None,
+      domain: String::new(),
+      url: Some(url.into()),
+      windows: Vec::new(),
+      plugins: Vec::new(),
+      enable_tauri_api: false,
+    }
+  }
+
+  #[test]
+  fn remote_domain_access_scope_matches_subdomain_and_path_wildcard() {
+    let scope = remote_domain_scope("https://*.example.com/app/*/edit");
+
+    assert!(scope.matches(&"https://docs.example.com/app/settings/edit".parse().unwrap()));
+    assert!(!scope.matches(&"https://example.com/app/settings/edit".parse().unwrap()));
+    assert!(!scope.matches(&"https://docs.example.com/app/settings/view".parse().unwrap()));
+    assert!(!scope.matches(&"https://docs.evil.com/app/settings/edit".parse().unwrap()));
+  }
+
+  #[test]
+  fn remote_domain_access_scope_named_path_segment() {
+    let scope = remote_domain_scope("https://example.com/users/:id");
+
+    assert!(scope.matches(&"https://example.com/users/42".parse().unwrap()));
+    assert!(!scope.matches(&"https://example.com/users/42/edit".parse().unwrap()));
+  }
+
+  #[test]
+  fn remote_domain_access_scope_defaults_protocol_to_https() {
+    let scope = remote_domain_scope("example.com/*");
+
+    assert!(scope.matches(&"https://example.com/foo".parse().unwrap()));
+    assert!(!scope.matches(&"http://example.com/foo".parse().unwrap()));
+  }
+
+  #[test]
+  fn match_pathname_wildcard_segment_requires_non_empty_segment() {
+    assert!(match_pathname("/app/*/edit", "/app/settings/edit"));
+    assert!(!match_pathname("/app/*/edit", "/app//edit"));
+    assert!(!match_pathname("/app/*/edit", "/app/edit"));
+  }
+
+  #[test]
+  fn match_hostname_leading_wildcard_label() {
+    assert!(match_hostname("*.example.com", "docs.example.com"));
+    assert!(!match_hostname("*.example.com", "example.com"));
+    assert!(!match_hostname("*.example.com", "a.b.example.com"));
+  }
 }
@@ -135,13 +135,151 @@ pub enum ConfigError {
     /// The [`std::io::Error`].
     error: std::io::Error,
   },
+
+  /// Failed to parse an inline config override (e.g. from `--config` or the `TAURI_CONFIG`
+  /// environment variable) as JSON. There's no file path to report here, so the offending snippet
+  /// is included instead.
+  #[error("unable to parse inline Tauri config override `{raw}` because {error}")]
+  InlineOverride {
+    /// The raw override string that failed to parse.
+    raw: String,
+
+    /// The parsing [`serde_json::Error`].
+    error: serde_json::Error,
+  },
+
+  /// The merged config value doesn't conform to the [`Config`](super::Config) JSON schema. Unlike
+  /// a plain deserialize error, this reports every offending node at once, each addressed by its
+  /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901).
+  #[cfg(feature = "config-schema")]
+  #[error(
+    "Tauri config schema violations:\n{}",
+    .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n")
+  )]
+  SchemaViolation(Vec<SchemaViolation>),
+}
+
+/// A single JSON schema violation found by [`validate_schema`], addressed by the
+/// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) of the offending node.
+#[cfg(feature = "config-schema")]
+#[derive(Debug, Clone)]
+pub struct SchemaViolation {
+  /// JSON Pointer to the node that failed validation, e.g. `/bundle/targets/2`.
+  pub pointer: String,
+  /// What the schema expected at that node (type, allowed values, etc.).
+  pub message: String,
+}
+
+#[cfg(feature = "config-schema")]
+impl std::fmt::Display for SchemaViolation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}: {}", self.pointer, self.message)
+  }
+}
+
+/// Validates `value` against the [`Config`](super::Config) JSON schema, collecting every
+/// violation instead of stopping at the first one, so callers can surface a single,
+/// path-addressed error list instead of letting `serde` abort on the first mismatch.
+#[cfg(feature = "config-schema")]
+fn validate_schema(value: &Value) -> Result<(), ConfigError> {
+  let schema = schemars::schema_for!(super::Config);
+  let schema = serde_json::to_value(schema).expect("Config schema is always valid JSON");
+  let validator = jsonschema::validator_for(&schema).expect("Config schema is always valid");
+
+  let violations: Vec<SchemaViolation> = validator
+    .iter_errors(value)
+    .map(|error| SchemaViolation {
+      pointer: error.instance_path.to_string(),
+      message: error.to_string(),
+    })
+    .collect();
+
+  if violations.is_empty() {
+    Ok(())
+  } else {
+    Err(ConfigError::SchemaViolation(violations))
+  }
+}
+
+/// Parses the given path, falling back to the JSON5 and TOML equivalents (when their respective
+/// Cargo features are enabled) if the given path doesn't exist, then deep-merges the matching
+/// platform-specific override file (e.g. `tauri.linux.conf.json`, falling back to its own JSON5/
+/// TOML equivalents the same way the base file does) on top of it, if one exists, implementing
+/// [RFC 7386 JSON Merge Patch](https://datatracker.ietf.org/doc/html/rfc7386) via
+/// [`super::merge`]: for two objects, merge keys recursively; `null` in the overlay removes the
+/// key; any other overlay value (including arrays) replaces the base value wholesale.
+///
+/// Returns the merged JSON [`Value`] instead of [`Config`], along with every file path that
+/// contributed to it (base file first, platform overlay last, if one was merged in), so callers
+/// can report which files fed the final config.
+///
+/// This does not run [`validate_schema`]: a caller that also applies a `--config`/`TAURI_CONFIG`
+/// inline override (i.e. [`parse_value_with_override`]) needs that override folded in first, or a
+/// violation it introduces would slip past validation entirely. Use [`parse_value_with_override`]
+/// (with `inline_config: None` if there is no override) to get schema validation.
+pub fn parse_value(path: impl Into<PathBuf>) -> Result<(Value, Vec<PathBuf>), ConfigError> {
+  let (mut value, base_path) = do_parse(path.into())?;
+  let mut contributing = vec![base_path.clone()];
+
+  let overlay_path = base_path.with_file_name(ConfigFormat::Json.into_platform_file_name());
+  match do_parse::<Value>(overlay_path) {
+    Ok((overlay, overlay_path)) => {
+      super::merge(&mut value, overlay);
+      contributing.push(overlay_path);
+    }
+    Err(ConfigError::Io { error, .. }) if error.kind() == std::io::ErrorKind::NotFound => {}
+    Err(error) => return Err(error),
+  }
+
+  Ok((value, contributing))
 }
 
-/// See [`parse`] for specifics, returns a JSON [`Value`] instead of [`Config`].
-pub fn parse_value(path: impl Into<PathBuf>) -> Result<(Value, PathBuf), ConfigError> {
-  do_parse(path.into())
+/// Parses `path` the same way [`parse_value`] does (base file, merged with its platform
+/// overlay if any), then merges `inline_config` — a raw JSON string such as the one passed via a
+/// `--config` CLI flag or the `TAURI_CONFIG` environment variable — on top of the result using the
+/// same JSON Merge Patch semantics, applied last so it can override anything the files set (e.g.
+/// CI pipelines tweaking `version`, `identifier`, or bundle targets without editing files).
+///
+/// When the `config-schema` feature is enabled, the fully-merged value (files and inline override
+/// alike) is validated against the [`Config`](super::Config) JSON schema before being returned,
+/// surfacing every violation (each addressed by its JSON Pointer) as a single
+/// [`ConfigError::SchemaViolation`] instead of letting `serde` abort on the first mismatched field
+/// during deserialization, or letting a bad override slip through unvalidated.
+pub fn parse_value_with_override(
+  path: impl Into<PathBuf>,
+  inline_config: Option<&str>,
+) -> Result<(Value, Vec<PathBuf>), ConfigError> {
+  let (mut value, contributing) = parse_value(path)?;
+
+  if let Some(raw) = inline_config {
+    let patch: Value = serde_json::from_str(raw).map_err(|error| ConfigError::InlineOverride {
+      raw: raw.to_string(),
+      error,
+    })?;
+    super::merge(&mut value, patch);
+  }
+
+  #[cfg(feature = "config-schema")]
+  validate_schema(&value)?;
+
+  Ok((value, contributing))
+}
+
+/// Returns the platform-specific configuration file names (e.g. `tauri.linux.conf.json`) that
+/// apply to the current `target_os`, one per currently-enabled [`ConfigFormat`]. A caller looking
+/// to honor the "Platform-Specific Configuration" behavior documented on [`super::Config`] should
+/// parse whichever of these exists alongside the base config file and
+/// [`super::merge`] it into the base value before deserializing.
+pub fn current_platform_overlay_filenames() -> Vec<&'static str> {
+  ENABLED_FORMATS
+    .iter()
+    .map(|format| format.into_platform_file_name())
+    .collect()
 }
 
+/// Looks up the configuration file at `path`, falling back to its JSON5 and TOML equivalents (in
+/// that order) when it doesn't exist. The file at `path` wins whenever it exists, so when `path`
+/// names an existing `tauri.conf.json`, a `Tauri.toml` or `tauri.conf.json5` beside it is ignored.
 fn do_parse<D: DeserializeOwned>(path: PathBuf) -> Result<(D, PathBuf), ConfigError> {
   let file_name = path
     .file_name()
@@ -0,0 +1,65 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Compile-time metadata describing plugin-provided global API scripts.
+//!
+//! A plugin may ship a small JavaScript file that augments `window.__TAURI__`, but that only
+//! needs to exist in the binary when the app opts into
+//! [`BuildConfig::with_global_tauri`](crate::config_v1::BuildConfig::with_global_tauri). Rather
+//! than every plugin unconditionally `include_str!`-ing its script, it declares the script's path
+//! in a [`PluginManifest`]; [`plugins_requiring_global_api_script`] tells the build/codegen layer
+//! which plugins actually need theirs embedded (via `include_str!`, producing a [`GlobalApiScript`])
+//! for the app's current configuration.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config_v1::PluginConfig;
+
+/// Compile-time metadata for a single plugin, keyed by plugin name in the manifest map passed to
+/// [`plugins_requiring_global_api_script`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginManifest {
+  /// Path, relative to the plugin's crate root, to a JavaScript file that augments
+  /// `window.__TAURI__`. Only embedded into the binary when global Tauri is enabled.
+  pub global_api_script: Option<PathBuf>,
+}
+
+/// A plugin's global API script, embedded into the binary at compile time (typically via
+/// `include_str!` in generated build-script code) and keyed by the plugin name it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlobalApiScript {
+  /// The name of the plugin this script belongs to.
+  pub plugin_name: &'static str,
+  /// The script's contents.
+  pub source: &'static str,
+}
+
+/// Returns the names of the plugins in `manifests` whose [`PluginManifest::global_api_script`]
+/// should be embedded for the current build: `with_global_tauri` must be enabled, the plugin must
+/// declare a `global_api_script`, and the plugin must actually be configured in `plugins` (an
+/// unconfigured plugin isn't in use, so its script would never run).
+///
+/// When `with_global_tauri` is `false` this always returns an empty list, so a build script that
+/// checks it before reaching for `include_str!` keeps every plugin's script text out of the binary.
+pub fn plugins_requiring_global_api_script<'a>(
+  manifests: &'a HashMap<String, PluginManifest>,
+  plugins: &PluginConfig,
+  with_global_tauri: bool,
+) -> Vec<&'a str> {
+  if !with_global_tauri {
+    return Vec::new();
+  }
+
+  manifests
+    .iter()
+    .filter(|(name, manifest)| {
+      manifest.global_api_script.is_some() && plugins.0.contains_key(name.as_str())
+    })
+    .map(|(name, _)| name.as_str())
+    .collect()
+}